@@ -908,7 +908,7 @@ function PlanForm({
           disabled={disabled}
         />
         <p className="text-xs text-muted-foreground">
-          Use 5-part cron format: minute hour day month weekday
+          Use 5-part cron format: minute hour day month weekday, or a macro such as @daily
         </p>
       </div>
 
@@ -1,28 +1,44 @@
 use axum::{
     Json, Router,
-    extract::{Request, State},
-    http::{HeaderMap, StatusCode},
+    body::{Body, Bytes},
+    extract::{Extension, Path, Query, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
     middleware::{self, Next},
-    response::Response,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
     routing::{get, post},
 };
-use chrono::{Datelike, Duration as ChronoDuration, Local, Timelike};
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, FixedOffset, NaiveDate, NaiveDateTime,
+    Timelike, Utc, Weekday,
+};
+use futures_util::stream::{self, Stream, StreamExt};
+use jiff::{Timestamp, tz::TimeZone};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{
-    collections::{HashMap, HashSet},
-    env,
-    fs,
+    collections::{BTreeSet, HashMap, HashSet},
+    convert::Infallible,
+    env, fs,
+    hash::{BuildHasher, RandomState},
+    io::Write,
     net::{SocketAddr, ToSocketAddrs},
     path::PathBuf,
-    process::Command,
+    process::Stdio,
     sync::{
         Arc, Mutex,
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicI64, AtomicU64, Ordering},
     },
     time::{Instant, SystemTime, UNIX_EPOCH},
 };
-use tokio::time::{self, Duration};
+use tokio::{
+    io::AsyncReadExt,
+    process::Command,
+    sync::{Mutex as AsyncMutex, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore, mpsc, watch},
+    time::{self, Duration},
+};
 
 #[derive(Clone)]
 struct AppState {
@@ -36,8 +52,23 @@ struct AppState {
     started_at: Instant,
     client: reqwest::Client,
     synced_backup_plans: Arc<Mutex<Vec<SyncedBackupPlan>>>,
-    executed_plan_ticks: Arc<Mutex<HashSet<String>>>,
+    plans_synced_at: Arc<AtomicI64>,
+    plans_version: Arc<Mutex<Option<String>>>,
+    plan_cache_max_age_minutes: u32,
+    plan_last_ticks: Arc<Mutex<HashMap<String, i64>>>,
+    plan_last_prunes: Arc<Mutex<HashMap<String, i64>>>,
+    reported_invalid_schedules: Arc<Mutex<HashSet<String>>>,
+    catch_up_window_minutes: u32,
     pending_reports: Arc<Mutex<Vec<PendingReport>>>,
+    active_plan_runs: Arc<Mutex<HashMap<String, ActivePlanRuns>>>,
+    deferred_plan_runs: Arc<Mutex<HashMap<String, ScheduledPlanRun>>>,
+    blackout_windows: Arc<Vec<BlackoutWindow>>,
+    command_timeout_seconds: u64,
+    default_backup_host: Option<String>,
+    operations: Arc<Mutex<HashMap<String, RunningOperation>>>,
+    jitter_seed: u64,
+    operation_slots: Arc<Semaphore>,
+    repository_locks: Arc<Mutex<HashMap<String, Arc<AsyncMutex<()>>>>>,
 }
 
 #[derive(Serialize)]
@@ -48,6 +79,113 @@ struct WorkerSyncPayload {
     uptime_ms: u64,
     requests_total: u64,
     error_total: u64,
+    jobs: Vec<JobResponse>,
+}
+
+#[derive(Clone)]
+struct OperationContext {
+    id: String,
+    timeout: Option<Duration>,
+    cancelled: watch::Receiver<bool>,
+    status: Arc<Mutex<OperationStatus>>,
+    progress: Arc<watch::Sender<Option<OperationProgress>>>,
+}
+
+#[derive(Serialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct OperationProgress {
+    message: Option<String>,
+    files_done: Option<u64>,
+    files_total: Option<u64>,
+    bytes_done: Option<u64>,
+    bytes_total: Option<u64>,
+    percent_done: Option<f64>,
+    eta_seconds: Option<u64>,
+    updated_at: String,
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum OperationPhase {
+    Starting,
+    Waiting,
+    Queued,
+    Running,
+    Terminating,
+}
+
+struct OperationStatus {
+    phase: OperationPhase,
+    repository: Option<String>,
+    pid: Option<u32>,
+}
+
+struct RunningOperation {
+    kind: String,
+    plan_id: Option<String>,
+    started_at: DateTime<Utc>,
+    started: Instant,
+    cancel: watch::Sender<bool>,
+    status: Arc<Mutex<OperationStatus>>,
+    progress: watch::Receiver<Option<OperationProgress>>,
+}
+
+struct OperationRegistration {
+    operations: Arc<Mutex<HashMap<String, RunningOperation>>>,
+    id: String,
+}
+
+impl Drop for OperationRegistration {
+    fn drop(&mut self) {
+        if let Ok(mut operations) = self.operations.lock() {
+            operations.remove(&self.id);
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobResponse {
+    id: String,
+    kind: String,
+    plan_id: Option<String>,
+    repository: Option<String>,
+    started_at: String,
+    elapsed_ms: u64,
+    pid: Option<u32>,
+    phase: OperationPhase,
+    cancelled: bool,
+    progress: Option<OperationProgress>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobsResponse {
+    jobs: Vec<JobResponse>,
+}
+
+enum ProcessError {
+    Io(std::io::Error),
+    TimedOut(Duration),
+    Cancelled,
+}
+
+#[derive(Clone, Copy)]
+enum ProcessSignal {
+    Terminate,
+    Kill,
+}
+
+struct ProcessGroupGuard {
+    pid: Option<u32>,
+}
+
+impl Drop for ProcessGroupGuard {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            signal_process_group(pid, ProcessSignal::Kill);
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -98,44 +236,382 @@ struct RusticStatsResponse {
     rustic: RusticCommandResult,
 }
 
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct RusticBackupRequest {
     repository: String,
     password: Option<String>,
     backend: Option<String>,
     options: Option<HashMap<String, String>>,
+    #[serde(default)]
     paths: Vec<String>,
     tags: Option<Vec<String>>,
     dry_run: Option<bool>,
+    #[serde(default)]
+    hooks: BackupHooks,
+    #[serde(default)]
+    source: Option<BackupSource>,
+    #[serde(default)]
+    globs: Vec<String>,
+    #[serde(default)]
+    iglobs: Vec<String>,
+    #[serde(default)]
+    glob_files: Vec<String>,
+    #[serde(default)]
+    iglob_files: Vec<String>,
+    #[serde(default)]
+    exclude_if_present: Vec<String>,
+    #[serde(default)]
+    exclude_larger_than: Option<String>,
+    #[serde(default)]
+    one_file_system: bool,
+    #[serde(default)]
+    git_ignore: bool,
+    #[serde(default)]
+    no_require_git: bool,
+    #[serde(default)]
+    host: Option<String>,
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    parent: Option<String>,
+    #[serde(default)]
+    force: bool,
+    #[serde(default)]
+    ignore_ctime: bool,
+    #[serde(default)]
+    ignore_inode: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+#[serde(
+    tag = "type",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+enum BackupSource {
+    Postgres {
+        #[serde(default)]
+        host: Option<String>,
+        #[serde(default)]
+        port: Option<u16>,
+        #[serde(default)]
+        username: Option<String>,
+        #[serde(default)]
+        password: Option<String>,
+        database: String,
+        #[serde(default)]
+        filename: Option<String>,
+    },
+    Mysql {
+        #[serde(default)]
+        host: Option<String>,
+        #[serde(default)]
+        port: Option<u16>,
+        #[serde(default)]
+        username: Option<String>,
+        #[serde(default)]
+        password: Option<String>,
+        database: String,
+        #[serde(default)]
+        filename: Option<String>,
+    },
+    Sqlite {
+        path: String,
+        #[serde(default)]
+        filename: Option<String>,
+    },
+}
+
+struct DumpCommand {
+    tool: &'static str,
+    args: Vec<String>,
+    env_vars: Vec<(String, String)>,
+    preview: Vec<String>,
+    filename: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+struct BackupHooks {
+    #[serde(default)]
+    pre: Vec<BackupHook>,
+    #[serde(default)]
+    post: Vec<BackupHook>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct BackupHook {
+    command: Vec<String>,
+    #[serde(default)]
+    env: HashMap<String, String>,
+    #[serde(default)]
+    on_failure: HookFailurePolicy,
+    #[serde(default)]
+    timeout_seconds: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+enum HookFailurePolicy {
+    #[default]
+    Abort,
+    Continue,
+    RunPostAnyway,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupHookResult {
+    stage: &'static str,
+    command: Vec<String>,
+    success: bool,
+    exit_code: Option<i32>,
+    timed_out: bool,
+    duration_ms: u64,
+    stdout: String,
+    stderr: String,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct SyncedBackupPlan {
     id: String,
     cron: String,
+    #[serde(default)]
+    time_zone: Option<String>,
+    #[serde(default)]
+    overlap_policy: OverlapPolicy,
+    #[serde(default)]
+    jitter_seconds: u32,
+    #[serde(default)]
+    jitter_mode: JitterMode,
+    #[serde(default)]
+    blackout_windows: Vec<String>,
+    #[serde(default)]
+    retry: RetryPolicy,
+    #[serde(default)]
+    timeout_seconds: Option<u64>,
+    #[serde(default)]
+    retention: Option<PlanRetention>,
+    #[serde(default)]
+    operation: PlanOperation,
+    #[serde(default)]
+    read_data_subset: Option<String>,
     request: RusticBackupRequest,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum PlanOperation {
+    #[default]
+    Backup,
+    Check,
+    CheckReadDataSubset,
+    Forget,
+    Prune,
+    RepairIndex,
+}
+
+impl PlanOperation {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Backup => "backup",
+            Self::Check => "check",
+            Self::CheckReadDataSubset => "check_read_data_subset",
+            Self::Forget => "forget",
+            Self::Prune => "prune",
+            Self::RepairIndex => "repair_index",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff_seconds: u64,
+    max_backoff_seconds: u64,
+    retry_on: Vec<BackupErrorClass>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff_seconds: DEFAULT_RETRY_INITIAL_BACKOFF_SECONDS,
+            max_backoff_seconds: DEFAULT_RETRY_MAX_BACKOFF_SECONDS,
+            retry_on: vec![
+                BackupErrorClass::Network,
+                BackupErrorClass::Backend,
+                BackupErrorClass::Locked,
+            ],
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum BackupErrorClass {
+    Network,
+    Backend,
+    Locked,
+    InvalidRequest,
+    Timeout,
+    Hook,
+    Other,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum JitterMode {
+    #[default]
+    Deterministic,
+    Random,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum OverlapPolicy {
+    #[default]
+    Skip,
+    QueueOne,
+    Allow,
+}
+
+struct ScheduledPlanRun {
+    plan: SyncedBackupPlan,
+    time_zone: TimeZone,
+    blackout_windows: Vec<BlackoutWindow>,
+    scheduled_at: i64,
+    late: bool,
+}
+
+#[derive(Clone)]
+enum BlackoutPeriod {
+    Weekly {
+        days: [bool; 7],
+        start_minute: u32,
+        end_minute: u32,
+    },
+    Once {
+        starts_at: i64,
+        ends_at: i64,
+    },
+}
+
+#[derive(Clone)]
+struct BlackoutWindow {
+    spec: String,
+    period: BlackoutPeriod,
+    stop_running: bool,
+}
+
+#[derive(Default)]
+struct ActivePlanRuns {
+    running: usize,
+    queued: Option<ScheduledPlanRun>,
+}
+
+enum PlanRunAdmission {
+    Start(ScheduledPlanRun),
+    Queued,
+    Skipped(ScheduledPlanRun, String),
+}
+
+#[derive(Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct BackupPlanSyncResponse {
+    #[serde(default)]
+    version: Option<String>,
     plans: Vec<SyncedBackupPlan>,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedBackupPlans {
+    synced_at: i64,
+    response: BackupPlanSyncResponse,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 struct BackupPlanReportRequest {
+    #[serde(default)]
+    operation: PlanOperation,
     status: String,
     error: Option<String>,
     duration_ms: u64,
     snapshot_id: Option<String>,
     snapshot_time: Option<String>,
     next_run_at: Option<String>,
+    #[serde(default)]
+    scheduled_at: Option<String>,
+    #[serde(default)]
+    late: bool,
+    #[serde(default)]
+    queued_ms: u64,
+    #[serde(default)]
+    attempts: Vec<BackupAttemptReport>,
     output: Value,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct BackupAttemptReport {
+    attempt: u32,
+    status: String,
+    error: Option<String>,
+    error_class: Option<BackupErrorClass>,
+    duration_ms: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimBackupRunsRequest {
+    limit: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimedBackupRun {
+    id: String,
+    plan_id: String,
+    request: RusticBackupRequest,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimBackupRunsResponse {
+    runs: Vec<ClaimedBackupRun>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpcomingRunsQuery {
+    count: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlanUpcomingRuns {
+    plan_id: String,
+    operation: PlanOperation,
+    cron: String,
+    time_zone: Option<String>,
+    next_runs: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpcomingRunsResponse {
+    plans: Vec<PlanUpcomingRuns>,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct RusticInitRequest {
@@ -156,6 +632,33 @@ struct RusticRepositoryRequest {
 
 const PENDING_REPORTS_MAX: usize = 500;
 const PENDING_REPORT_MAX_ATTEMPTS: u32 = 20;
+const CLAIM_BACKUP_RUNS_LIMIT: u32 = 3;
+const DEFAULT_CATCH_UP_WINDOW_MINUTES: u32 = 60;
+const DST_SCAN_SLACK_MINUTES: i64 = 180;
+const DEFAULT_MAX_CONCURRENT_OPERATIONS: usize = 2;
+const DEFAULT_PLAN_CACHE_MAX_AGE_MINUTES: u32 = 1440;
+const DEFAULT_COMMAND_TIMEOUT_SECONDS: u64 = 0;
+const PROCESS_TERMINATE_GRACE: Duration = Duration::from_secs(10);
+const OPERATION_ID_HEADER: &str = "x-operation-id";
+const REPOSITORY_HEADER: &str = "x-repository";
+const REPOSITORY_BACKEND_HEADER: &str = "x-repository-backend";
+const REPOSITORY_OPTIONS_HEADER: &str = "x-repository-options";
+const REPOSITORY_PASSWORD_HEADER: &str = "x-repository-password";
+const BACKUP_STREAM_BUFFER_CHUNKS: usize = 16;
+const PROGRESS_INTERVAL: &str = "1s";
+const DEFAULT_HOOK_TIMEOUT_SECONDS: u64 = 600;
+const DEFAULT_READ_DATA_SUBSET: &str = "10%";
+const MAX_HOOK_OUTPUT_BYTES: usize = 64 * 1024;
+const CRON_SEARCH_YEARS: i32 = 50;
+const DEFAULT_UPCOMING_RUNS: usize = 5;
+const MAX_UPCOMING_RUNS: usize = 100;
+const MAX_JITTER_SECONDS: u32 = 3600;
+const MAX_RETRY_ATTEMPTS: u32 = 10;
+const DEFAULT_RETRY_INITIAL_BACKOFF_SECONDS: u64 = 30;
+const DEFAULT_RETRY_MAX_BACKOFF_SECONDS: u64 = 900;
+const FORGET_GROUP_CRITERIA: [&str; 4] = ["host", "label", "paths", "tags"];
+const BASE32_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -221,20 +724,117 @@ struct RusticForgetRequest {
     password: Option<String>,
     backend: Option<String>,
     options: Option<HashMap<String, String>>,
+    #[serde(flatten)]
+    retention: RetentionPolicy,
+    #[serde(default)]
+    keep_none: bool,
+    group_by: Option<String>,
+    #[serde(default)]
+    filter_hosts: Vec<String>,
+    #[serde(default)]
+    filter_tags: Vec<String>,
+    #[serde(default)]
+    filter_paths: Vec<String>,
+    prune: Option<bool>,
+    dry_run: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+struct RetentionPolicy {
     keep_last: Option<u32>,
+    keep_hourly: Option<u32>,
     keep_daily: Option<u32>,
     keep_weekly: Option<u32>,
     keep_monthly: Option<u32>,
     keep_yearly: Option<u32>,
     keep_within: Option<String>,
-    prune: Option<bool>,
-    dry_run: Option<bool>,
+    #[serde(default)]
+    keep_tags: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct PlanRetention {
+    #[serde(flatten)]
+    keep: RetentionPolicy,
+    #[serde(default)]
+    prune_interval_hours: u32,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RetentionRunReport {
+    forget: Option<RusticCommandResult>,
+    prune: Option<RusticCommandResult>,
+    error: Option<String>,
 }
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct RusticForgetResponse {
     worker: WorkerRuntimeStats,
+    groups: Vec<ForgetGroupSummary>,
+    rustic: RusticCommandResult,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ForgetGroupSummary {
+    group: Value,
+    kept: Vec<ForgetSnapshotSummary>,
+    removed: Vec<ForgetSnapshotSummary>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ForgetSnapshotSummary {
+    id: String,
+    time: Option<String>,
+    reasons: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RusticPruneRequest {
+    repository: String,
+    password: Option<String>,
+    backend: Option<String>,
+    options: Option<HashMap<String, String>>,
+    max_unused: Option<String>,
+    max_repack: Option<String>,
+    keep_pack: Option<String>,
+    keep_delete: Option<String>,
+    repack_cacheable_only: Option<bool>,
+    dry_run: Option<bool>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct PruneStats {
+    packs_to_repack: Option<u64>,
+    blobs_to_repack: Option<u64>,
+    bytes_to_repack: Option<u64>,
+    blobs_removed_by_repack: Option<u64>,
+    bytes_removed_by_repack: Option<u64>,
+    packs_to_delete: Option<u64>,
+    blobs_to_delete: Option<u64>,
+    bytes_to_delete: Option<u64>,
+    blobs_pruned: Option<u64>,
+    bytes_freed: Option<u64>,
+    blobs_remaining: Option<u64>,
+    bytes_remaining: Option<u64>,
+    unused_bytes_after_prune: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RusticPruneResponse {
+    worker: WorkerRuntimeStats,
+    dry_run: bool,
+    stats: PruneStats,
+    stats_best_effort: bool,
+    summary: String,
     rustic: RusticCommandResult,
 }
 
@@ -243,6 +843,25 @@ struct RusticForgetResponse {
 struct RusticBackupResponse {
     worker: WorkerRuntimeStats,
     rustic: RusticCommandResult,
+    hooks: Vec<BackupHookResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RusticBackupStreamQuery {
+    filename: String,
+    tags: Option<String>,
+    dry_run: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RusticBackupStreamResponse {
+    worker: WorkerRuntimeStats,
+    snapshot_id: Option<String>,
+    snapshot_time: Option<String>,
+    bytes_received: u64,
+    rustic: RusticCommandResult,
 }
 
 #[derive(Serialize)]
@@ -304,16 +923,14 @@ fn strip_ansi_codes(input: &str) -> String {
     let mut chars = input.chars().peekable();
 
     while let Some(ch) = chars.next() {
-        if ch == '\u{1b}' {
-            if matches!(chars.peek(), Some('[')) {
-                let _ = chars.next();
-                for c in chars.by_ref() {
-                    if c.is_ascii_alphabetic() {
-                        break;
-                    }
+        if ch == '\u{1b}' && matches!(chars.peek(), Some('[')) {
+            let _ = chars.next();
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
                 }
-                continue;
             }
+            continue;
         }
         output.push(ch);
     }
@@ -335,8 +952,8 @@ fn first_useful_error_line(stderr: &str) -> Option<String> {
 
     for (idx, line) in lines.iter().enumerate() {
         let normalized = line.to_ascii_lowercase();
-        if normalized == "message:" {
-            if let Some(next) = lines
+        if normalized == "message:"
+            && let Some(next) = lines
                 .iter()
                 .skip(idx + 1)
                 .find(|candidate| {
@@ -346,9 +963,8 @@ fn first_useful_error_line(stderr: &str) -> Option<String> {
                         && !n.starts_with("backtrace")
                 })
                 .cloned()
-            {
-                return Some(next);
-            }
+        {
+            return Some(next);
         }
     }
 
@@ -362,12 +978,18 @@ fn first_useful_error_line(stderr: &str) -> Option<String> {
         }
     }
 
-    lines
+    let candidates = lines
         .into_iter()
-        .find(|line| {
+        .filter(|line| {
             let normalized = line.to_ascii_lowercase();
             !normalized.contains("[info]") && !normalized.starts_with("info:")
         })
+        .collect::<Vec<_>>();
+    candidates
+        .iter()
+        .find(|line| parse_progress_line(line).is_none())
+        .or_else(|| candidates.first())
+        .cloned()
 }
 
 #[tokio::main]
@@ -383,8 +1005,19 @@ async fn main() {
         });
 
     let pending_reports = load_pending_reports(&cli.state_dir);
+    let plan_last_ticks = load_plan_ticks(&cli.state_dir);
+    let plan_last_prunes = load_plan_prunes(&cli.state_dir);
+    let (cached_plans, plans_version, plans_synced_at) =
+        match load_cached_backup_plans(&cli.state_dir) {
+            Some(cached) => (
+                cached.response.plans,
+                cached.response.version,
+                cached.synced_at,
+            ),
+            None => (Vec::new(), None, 0),
+        };
     let state = AppState {
-        bearer_token: cli.api_token,
+        bearer_token: cli.api_token.clone(),
         master_api_endpoint: cli.master_api_endpoint,
         local_api_endpoint: cli.local_api_endpoint.clone(),
         rustic_bin: cli.rustic_bin,
@@ -393,24 +1026,53 @@ async fn main() {
         error_total: Arc::new(AtomicU64::new(0)),
         started_at: Instant::now(),
         client: reqwest::Client::new(),
-        synced_backup_plans: Arc::new(Mutex::new(Vec::new())),
-        executed_plan_ticks: Arc::new(Mutex::new(HashSet::new())),
+        synced_backup_plans: Arc::new(Mutex::new(cached_plans)),
+        plans_synced_at: Arc::new(AtomicI64::new(plans_synced_at)),
+        plans_version: Arc::new(Mutex::new(plans_version)),
+        plan_cache_max_age_minutes: cli.plan_cache_max_age_minutes,
+        plan_last_ticks: Arc::new(Mutex::new(plan_last_ticks)),
+        plan_last_prunes: Arc::new(Mutex::new(plan_last_prunes)),
+        reported_invalid_schedules: Arc::new(Mutex::new(HashSet::new())),
+        catch_up_window_minutes: cli.catch_up_window_minutes,
         pending_reports: Arc::new(Mutex::new(pending_reports)),
+        active_plan_runs: Arc::new(Mutex::new(HashMap::new())),
+        deferred_plan_runs: Arc::new(Mutex::new(HashMap::new())),
+        blackout_windows: Arc::new(cli.blackout_windows),
+        command_timeout_seconds: cli.command_timeout_seconds,
+        default_backup_host: cli.default_host.or_else(|| {
+            worker_id_from_token(&cli.api_token)
+                .filter(|_| cli.host_from_worker_id)
+                .map(|id| format!("glare-{id}"))
+        }),
+        operations: Arc::new(Mutex::new(HashMap::new())),
+        jitter_seed: RandomState::new().hash_one(Instant::now()),
+        operation_slots: Arc::new(Semaphore::new(cli.max_concurrent_operations)),
+        repository_locks: Arc::new(Mutex::new(HashMap::new())),
     };
 
     let app = Router::new()
         .route("/health", get(health))
         .route("/", get(index))
+        .route("/backup-plans/upcoming", get(upcoming_backup_runs))
+        .route("/jobs", get(list_jobs))
+        .route("/jobs/{id}", get(get_job))
+        .route("/jobs/{id}/progress", get(job_progress_events))
+        .route("/operations/{id}/cancel", post(cancel_operation))
         .route("/rustic/version", get(rustic_version))
         .route("/rustic/snapshots", get(rustic_snapshots))
-        .route("/rustic/repository-snapshots", post(rustic_repository_snapshots))
+        .route(
+            "/rustic/repository-snapshots",
+            post(rustic_repository_snapshots),
+        )
         .route("/rustic/snapshot/files", post(rustic_snapshot_files))
         .route("/rustic/check", post(rustic_check))
         .route("/rustic/repair-index", post(rustic_repair_index))
         .route("/rustic/stats", get(rustic_stats))
         .route("/rustic/init", post(rustic_init))
         .route("/rustic/backup", post(rustic_backup))
+        .route("/rustic/backup-stream", post(rustic_backup_stream))
         .route("/rustic/forget", post(rustic_forget))
+        .route("/rustic/prune", post(rustic_prune))
         .route("/rustic/restore", post(rustic_restore))
         .route("/rustic/ls-dirs", post(ls_dirs))
         .route("/rustic/rclone-size", post(rclone_size))
@@ -418,6 +1080,10 @@ async fn main() {
             state.clone(),
             request_logger,
         ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            track_operation,
+        ))
         .route_layer(middleware::from_fn_with_state(state.clone(), bearer_auth))
         .with_state(state.clone());
 
@@ -433,6 +1099,10 @@ async fn main() {
     tokio::spawn(async move {
         execute_synced_backup_plans_loop(plan_exec_state).await;
     });
+    let claim_state = state.clone();
+    tokio::spawn(async move {
+        claim_backup_runs_loop(claim_state).await;
+    });
     let flush_state = state.clone();
     tokio::spawn(async move {
         flush_pending_reports_loop(flush_state).await;
@@ -442,10 +1112,15 @@ async fn main() {
         .await
         .expect("failed to bind listener");
     log_info(format!("worker listening on http://{addr}"));
-    log_info(format!("master api endpoint: {}", state.master_api_endpoint));
+    log_info(format!(
+        "master api endpoint: {}",
+        state.master_api_endpoint
+    ));
     log_info(format!("local api endpoint: {}", state.local_api_endpoint));
 
-    axum::serve(listener, app).await.expect("worker server failed");
+    axum::serve(listener, app)
+        .await
+        .expect("worker server failed");
 }
 
 async fn health() -> &'static str {
@@ -454,24 +1129,96 @@ async fn health() -> &'static str {
 
 async fn rclone_size(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
     Json(payload): Json<RcloneSizeRequest>,
 ) -> Result<Json<RcloneSizeResponse>, (StatusCode, Json<ApiErrorResponse>)> {
     let remote = payload.remote.trim().to_string();
     if remote.is_empty() {
         return Err(api_error(StatusCode::BAD_REQUEST, "remote is required"));
     }
+    let _slot =
+        acquire_operation_slot(&state, &operation, &remote, RepositoryAccess::Shared).await?;
 
     let worker = worker_runtime_stats(&state);
     let rclone = run_rclone_command(
         &state,
+        &operation,
         vec!["size".to_string(), remote.clone(), "--json".to_string()],
-        vec!["rclone".to_string(), "size".to_string(), remote, "--json".to_string()],
+        vec![
+            "rclone".to_string(),
+            "size".to_string(),
+            remote,
+            "--json".to_string(),
+        ],
     )
     .await?;
 
     Ok(Json(RcloneSizeResponse { worker, rclone }))
 }
 
+async fn upcoming_backup_runs(
+    State(state): State<AppState>,
+    Query(query): Query<UpcomingRunsQuery>,
+) -> Result<Json<UpcomingRunsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let count = query.count.unwrap_or(DEFAULT_UPCOMING_RUNS);
+    if count == 0 || count > MAX_UPCOMING_RUNS {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            format!("count must be between 1 and {MAX_UPCOMING_RUNS}"),
+        ));
+    }
+
+    let plans = state
+        .synced_backup_plans
+        .lock()
+        .map(|guard| guard.clone())
+        .map_err(|_| {
+            api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to lock synced backup plans cache",
+            )
+        })?;
+
+    let now = Utc::now().timestamp();
+    let plans = plans
+        .into_iter()
+        .map(|plan| {
+            let schedule = parse_cron_expression(&plan.cron)
+                .map_err(|reason| format!("invalid cron '{}': {reason}", plan.cron))
+                .and_then(|parsed| {
+                    resolve_plan_time_zone(plan.time_zone.as_deref())
+                        .map(|time_zone| (parsed, time_zone))
+                        .map_err(|reason| format!("invalid time zone: {reason}"))
+                });
+            let (next_runs, error) = match schedule {
+                Ok((parsed, time_zone)) => (
+                    next_cron_occurrences(&parsed, &time_zone, now, count)
+                        .into_iter()
+                        .filter_map(|timestamp| {
+                            format_timestamp(
+                                &time_zone,
+                                timestamp + plan_start_delay_seconds(&state, &plan, timestamp),
+                            )
+                        })
+                        .collect(),
+                    None,
+                ),
+                Err(error) => (Vec::new(), Some(error)),
+            };
+            PlanUpcomingRuns {
+                plan_id: plan.id,
+                operation: plan.operation,
+                cron: plan.cron,
+                time_zone: plan.time_zone,
+                next_runs,
+                error,
+            }
+        })
+        .collect();
+
+    Ok(Json(UpcomingRunsResponse { plans }))
+}
+
 async fn index() -> &'static str {
     "worker up"
 }
@@ -527,65 +1274,692 @@ fn parse_rustic_json_output(stdout: &str) -> Option<Value> {
     }
 }
 
-async fn run_rustic_command(
+fn new_operation_id() -> String {
+    let high = RandomState::new().hash_one(Instant::now());
+    let low = RandomState::new().hash_one(high);
+    format!("{high:016x}{low:016x}")
+}
+
+fn register_operation(
     state: &AppState,
-    args: Vec<String>,
-    env_vars: Vec<(String, String)>,
-    command_preview: Option<Vec<String>>,
-) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
-    let bin = state.rustic_bin.clone();
-    let state_dir = state.state_dir.clone();
-    let args_for_process = args.clone();
-    let env_vars_for_process = env_vars.clone();
-    let full_command = command_preview.unwrap_or_else(|| {
-        std::iter::once(bin.clone())
-            .chain(args.iter().cloned())
-            .collect::<Vec<_>>()
-    });
-    log_info(format!("executing rustic command: {}", full_command.join(" ")));
-
-    let output = tokio::task::spawn_blocking(move || {
-        let base = PathBuf::from(&state_dir);
-        let xdg_config_home = base.join("config");
-        let xdg_cache_home = base.join("cache");
-        let home_dir = base.join("home");
-        let rclone_config = xdg_config_home.join("rclone").join("rclone.conf");
-        let _ = fs::create_dir_all(home_dir.clone());
-        let _ = fs::create_dir_all(xdg_config_home.join("rclone"));
-        let _ = fs::create_dir_all(xdg_cache_home.clone());
-
-        let mut command = Command::new(&bin);
-        command.args(&args_for_process);
-        command.env("HOME", home_dir);
-        command.env("XDG_CONFIG_HOME", xdg_config_home);
-        command.env("XDG_CACHE_HOME", xdg_cache_home);
-        command.env("RCLONE_CONFIG", rclone_config);
-        command.env("RUSTIC_LOG_LEVEL", "warn");
-        for (key, value) in env_vars_for_process {
-            command.env(key, value);
-        }
-        command.output()
-    })
-    .await
-    .map_err(|error| {
+    id: Option<String>,
+    kind: &str,
+    plan_id: Option<String>,
+    timeout_seconds: Option<u64>,
+) -> Result<(OperationContext, OperationRegistration), (StatusCode, Json<ApiErrorResponse>)> {
+    let id = id.unwrap_or_else(new_operation_id);
+    let (cancel, cancelled) = watch::channel(false);
+    let status = Arc::new(Mutex::new(OperationStatus {
+        phase: OperationPhase::Starting,
+        repository: None,
+        pid: None,
+    }));
+    let (progress, progress_updates) = watch::channel(None);
+    let mut operations = state.operations.lock().map_err(|_| {
         api_error(
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("rustic command task failed: {error}"),
+            "failed to lock operation registry",
         )
-    })?
-    .map_err(|error| {
-        if error.kind() == std::io::ErrorKind::NotFound {
-            api_error(
-                StatusCode::SERVICE_UNAVAILABLE,
-                format!("rustic binary not found at '{}'", state.rustic_bin),
-            )
-        } else {
+    })?;
+    if operations.contains_key(&id) {
+        return Err(api_error(
+            StatusCode::CONFLICT,
+            format!("operation '{id}' is already running"),
+        ));
+    }
+    operations.insert(
+        id.clone(),
+        RunningOperation {
+            kind: kind.to_string(),
+            plan_id,
+            started_at: Utc::now(),
+            started: Instant::now(),
+            cancel,
+            status: status.clone(),
+            progress: progress_updates,
+        },
+    );
+
+    let timeout_seconds = timeout_seconds.unwrap_or(state.command_timeout_seconds);
+    Ok((
+        OperationContext {
+            id: id.clone(),
+            timeout: (timeout_seconds > 0).then(|| Duration::from_secs(timeout_seconds)),
+            cancelled,
+            status,
+            progress: Arc::new(progress),
+        },
+        OperationRegistration {
+            operations: state.operations.clone(),
+            id,
+        },
+    ))
+}
+
+fn operation_cancelled(operation: &OperationContext) -> bool {
+    *operation.cancelled.borrow()
+}
+
+async fn wait_for_cancellation(operation: &OperationContext) {
+    let mut cancelled = operation.cancelled.clone();
+    if cancelled.wait_for(|cancelled| *cancelled).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+async fn sleep_unless_cancelled(operation: &OperationContext, duration: Duration) {
+    set_operation_phase(operation, OperationPhase::Waiting);
+    tokio::select! {
+        () = time::sleep(duration) => {}
+        () = wait_for_cancellation(operation) => {}
+    }
+}
+
+fn update_operation_status(
+    operation: &OperationContext,
+    update: impl FnOnce(&mut OperationStatus),
+) {
+    if let Ok(mut status) = operation.status.lock() {
+        update(&mut status);
+    }
+}
+
+fn set_operation_phase(operation: &OperationContext, phase: OperationPhase) {
+    update_operation_status(operation, |status| status.phase = phase);
+}
+
+fn job_response(id: &str, operation: &RunningOperation) -> JobResponse {
+    let (phase, repository, pid) = operation
+        .status
+        .lock()
+        .map(|status| (status.phase, status.repository.clone(), status.pid))
+        .unwrap_or((OperationPhase::Starting, None, None));
+    JobResponse {
+        id: id.to_string(),
+        kind: operation.kind.clone(),
+        plan_id: operation.plan_id.clone(),
+        repository,
+        started_at: operation.started_at.to_rfc3339(),
+        elapsed_ms: operation.started.elapsed().as_millis() as u64,
+        pid,
+        phase,
+        cancelled: *operation.cancel.borrow(),
+        progress: operation.progress.borrow().clone(),
+    }
+}
+
+fn collect_jobs(state: &AppState) -> Vec<JobResponse> {
+    let Ok(operations) = state.operations.lock() else {
+        log_error("failed to lock operation registry");
+        return Vec::new();
+    };
+    let mut jobs = operations
+        .iter()
+        .map(|(id, operation)| job_response(id, operation))
+        .collect::<Vec<_>>();
+    jobs.sort_by(|a, b| {
+        a.started_at
+            .cmp(&b.started_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    jobs
+}
+
+async fn list_jobs(State(state): State<AppState>) -> Json<JobsResponse> {
+    Json(JobsResponse {
+        jobs: collect_jobs(&state),
+    })
+}
+
+async fn job_progress_events(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, Json<ApiErrorResponse>)>
+{
+    let mut progress = state
+        .operations
+        .lock()
+        .map_err(|_| {
             api_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
-                format!("failed to execute rustic: {error}"),
+                "failed to lock operation registry",
             )
+        })?
+        .get(&id)
+        .map(|operation| operation.progress.clone())
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("job '{id}' is not running")))?;
+    progress.mark_changed();
+
+    let events = stream::unfold(progress, |mut progress| async move {
+        loop {
+            progress.changed().await.ok()?;
+            let Some(update) = progress.borrow_and_update().clone() else {
+                continue;
+            };
+            let event = Event::default().event("progress").json_data(update).ok()?;
+            return Some((Ok(event), progress));
         }
+    });
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+async fn get_job(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let operations = state.operations.lock().map_err(|_| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to lock operation registry",
+        )
     })?;
+    operations
+        .get(&id)
+        .map(|operation| Json(job_response(&id, operation)))
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("job '{id}' is not running")))
+}
+
+async fn cancel_operation(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let operations = state.operations.lock().map_err(|_| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "failed to lock operation registry",
+        )
+    })?;
+    let operation = operations.get(&id).ok_or_else(|| {
+        api_error(
+            StatusCode::NOT_FOUND,
+            format!("operation '{id}' is not running"),
+        )
+    })?;
+
+    operation.cancel.send_replace(true);
+    log_warn(format!(
+        "cancelling operation id={id} kind={}",
+        operation.kind
+    ));
+    Ok(Json(job_response(&id, operation)))
+}
+
+async fn track_operation(State(state): State<AppState>, mut req: Request, next: Next) -> Response {
+    let Some(kind) = req
+        .uri()
+        .path()
+        .strip_prefix("/rustic/")
+        .map(str::to_string)
+    else {
+        return next.run(req).await;
+    };
+    let requested_id = req
+        .headers()
+        .get(OPERATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let (operation, _registration) =
+        match register_operation(&state, requested_id, &kind, None, None) {
+            Ok(registered) => registered,
+            Err(error) => return error.into_response(),
+        };
+
+    let id = operation.id.clone();
+    req.extensions_mut().insert(operation);
+    let mut response = next.run(req).await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(OPERATION_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(unix)]
+fn signal_process_group(pid: u32, signal: ProcessSignal) {
+    let signal = match signal {
+        ProcessSignal::Terminate => libc::SIGTERM,
+        ProcessSignal::Kill => libc::SIGKILL,
+    };
+    if let Ok(pid) = i32::try_from(pid) {
+        unsafe {
+            libc::kill(-pid, signal);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn signal_process_group(_pid: u32, _signal: ProcessSignal) {}
+
+fn parse_byte_unit(unit: &str) -> Option<f64> {
+    let multiplier = match unit {
+        "B" | "bytes" => 1.0,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        "PiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        "kB" | "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        "PB" => 1e15,
+        _ => return None,
+    };
+    Some(multiplier)
+}
+
+fn parse_eta_seconds(text: &str) -> Option<u64> {
+    let text = text.trim();
+    if text.contains(':') {
+        return text.split(':').try_fold(0u64, |total, part| {
+            part.trim()
+                .parse::<u64>()
+                .ok()
+                .map(|value| total * 60 + value)
+        });
+    }
+
+    let mut total = 0u64;
+    let mut pending: Option<u64> = None;
+    let mut parsed_any = false;
+    for token in text.split_whitespace() {
+        let digits_end = token
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(digits_end);
+        let value = if number.is_empty() {
+            pending.take()?
+        } else if unit.is_empty() {
+            pending = Some(number.parse().ok()?);
+            continue;
+        } else {
+            number.parse().ok()?
+        };
+        let seconds = match unit.trim_end_matches('s') {
+            "" => 1,
+            "m" | "min" | "minute" => 60,
+            "h" | "hour" => 3600,
+            "d" | "day" => 86400,
+            "w" | "week" => 604800,
+            "second" | "sec" => 1,
+            _ => return None,
+        };
+        total += value * seconds;
+        parsed_any = true;
+    }
+    parsed_any.then_some(total)
+}
+
+fn parse_progress_line(line: &str) -> Option<OperationProgress> {
+    let line = strip_ansi_codes(line);
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    let normalized = line.to_ascii_lowercase();
+    if normalized.contains("error") || normalized.contains("warn") {
+        return None;
+    }
+
+    let mut progress = OperationProgress::default();
+    let eta_index = line
+        .match_indices("ETA")
+        .map(|(index, _)| index)
+        .find(|index| {
+            line[..*index]
+                .chars()
+                .next_back()
+                .is_none_or(|ch| ch.is_whitespace() || ch == '(')
+                && line[index + 3..]
+                    .chars()
+                    .next()
+                    .is_none_or(|ch| ch.is_whitespace() || ch == ':')
+        });
+    let (body, eta) = match eta_index {
+        Some(index) => (&line[..index], Some(&line[index + 3..])),
+        None => (line, None),
+    };
+    progress.eta_seconds = eta.and_then(|eta| {
+        parse_eta_seconds(eta.trim_start_matches(':').split(')').next().unwrap_or(eta))
+    });
+
+    let body = body.trim_end().trim_end_matches('(');
+    let is_count_or_unit = |part: &str| {
+        part.is_empty()
+            || part.replace(',', "").parse::<f64>().is_ok()
+            || parse_byte_unit(part).is_some()
+    };
+    let tokens = body
+        .split_whitespace()
+        .flat_map(|token| match token.split_once('/') {
+            Some((left, right))
+                if !right.contains('/') && is_count_or_unit(left) && is_count_or_unit(right) =>
+            {
+                vec![left, "/", right]
+            }
+            _ => vec![token],
+        })
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+    for (index, token) in tokens.iter().enumerate() {
+        if *token != "/" || index == 0 || index + 1 >= tokens.len() {
+            continue;
+        }
+        if index >= 2
+            && let (Some(done_unit), Some(total_unit)) = (
+                parse_byte_unit(tokens[index - 1]),
+                tokens.get(index + 2).and_then(|unit| parse_byte_unit(unit)),
+            )
+            && let (Ok(done), Ok(total)) = (
+                tokens[index - 2].parse::<f64>(),
+                tokens[index + 1].parse::<f64>(),
+            )
+        {
+            progress.bytes_done = Some((done * done_unit) as u64);
+            progress.bytes_total = Some((total * total_unit) as u64);
+        } else if let (Ok(done), Ok(total)) = (
+            tokens[index - 1].replace(',', "").parse::<u64>(),
+            tokens[index + 1].replace(',', "").parse::<u64>(),
+        ) && tokens
+            .get(index + 2)
+            .is_none_or(|unit| parse_byte_unit(unit).is_none())
+        {
+            progress.files_done = Some(done);
+            progress.files_total = Some(total);
+        }
+    }
+    progress.percent_done = tokens
+        .iter()
+        .find_map(|token| token.strip_suffix('%')?.parse::<f64>().ok());
+
+    if progress.bytes_done.is_none()
+        && progress.files_done.is_none()
+        && progress.percent_done.is_none()
+        && progress.eta_seconds.is_none()
+    {
+        return None;
+    }
+
+    let mut unbracketed = String::with_capacity(body.len());
+    let mut depth = 0usize;
+    for ch in body.chars() {
+        match ch {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => unbracketed.push(ch),
+            _ => {}
+        }
+    }
+    let message = unbracketed
+        .split_whitespace()
+        .take_while(|token| {
+            !token.starts_with(|ch: char| ch.is_ascii_digit())
+                && !token.contains(['/', '%'])
+                && token.chars().any(char::is_alphanumeric)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    progress.message = (!message.is_empty()).then_some(message);
+    Some(progress)
+}
+
+fn publish_progress(operation: &OperationContext, update: OperationProgress) {
+    operation.progress.send_if_modified(|current| {
+        let mut next = current.clone().unwrap_or_default();
+        if update.message.is_some() {
+            next.message = update.message;
+        }
+        if update.files_done.is_some() {
+            next.files_done = update.files_done;
+            next.files_total = update.files_total;
+        }
+        if update.bytes_done.is_some() {
+            next.bytes_done = update.bytes_done;
+            next.bytes_total = update.bytes_total;
+        }
+        if update.eta_seconds.is_some() {
+            next.eta_seconds = update.eta_seconds;
+        }
+        next.percent_done = update.percent_done.or_else(|| {
+            match (
+                next.bytes_done,
+                next.bytes_total,
+                next.files_done,
+                next.files_total,
+            ) {
+                (Some(done), Some(total), _, _) | (None, _, Some(done), Some(total))
+                    if total > 0 =>
+                {
+                    Some((done as f64 / total as f64 * 1000.0).round() / 10.0)
+                }
+                _ => next.percent_done,
+            }
+        });
+        next.updated_at = String::new();
+        let unchanged = current.as_ref().is_some_and(|current| {
+            OperationProgress {
+                updated_at: String::new(),
+                ..current.clone()
+            } == next
+        });
+        if unchanged {
+            return false;
+        }
+        next.updated_at = Utc::now().to_rfc3339();
+        *current = Some(next);
+        true
+    });
+}
+
+async fn read_process_stderr(
+    mut stderr: Option<tokio::process::ChildStderr>,
+    operation: &OperationContext,
+    track_progress: bool,
+) -> std::io::Result<Vec<u8>> {
+    let Some(stderr) = stderr.as_mut() else {
+        return Ok(Vec::new());
+    };
+
+    let mut output = Vec::new();
+    if !track_progress {
+        stderr.read_to_end(&mut output).await?;
+        return Ok(output);
+    }
+    let mut pending = Vec::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        let read = stderr.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        pending.extend_from_slice(&buffer[..read]);
+        while let Some(index) = pending
+            .iter()
+            .position(|byte| *byte == b'\n' || *byte == b'\r')
+        {
+            let segment = pending.drain(..=index).collect::<Vec<_>>();
+            if segment.len() <= 1 {
+                continue;
+            }
+            if let Some(update) = parse_progress_line(&String::from_utf8_lossy(&segment)) {
+                publish_progress(operation, update);
+            }
+            output.extend_from_slice(&segment);
+        }
+    }
+    output.extend_from_slice(&pending);
+    Ok(output)
+}
+
+async fn run_child_process(
+    mut command: Command,
+    operation: &OperationContext,
+    track_progress: bool,
+) -> Result<std::process::Output, ProcessError> {
+    command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    #[cfg(unix)]
+    command.process_group(0);
+
+    let mut child = command.spawn().map_err(ProcessError::Io)?;
+    let mut guard = ProcessGroupGuard { pid: child.id() };
+    update_operation_status(operation, |status| {
+        status.phase = OperationPhase::Running;
+        status.pid = child.id();
+    });
+    let mut stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let output = async {
+        let read_stdout = async {
+            let mut output = Vec::new();
+            if let Some(stdout) = stdout.as_mut() {
+                stdout.read_to_end(&mut output).await?;
+            }
+            Ok::<_, std::io::Error>(output)
+        };
+        let (status, stdout, stderr) = tokio::try_join!(
+            child.wait(),
+            read_stdout,
+            read_process_stderr(stderr, operation, track_progress)
+        )?;
+        Ok(std::process::Output {
+            status,
+            stdout,
+            stderr,
+        })
+    };
+    tokio::pin!(output);
+
+    let deadline = async {
+        match operation.timeout {
+            Some(timeout) => time::sleep(timeout).await,
+            None => std::future::pending().await,
+        }
+    };
+    let stop_reason = tokio::select! {
+        output = &mut output => {
+            guard.pid = None;
+            update_operation_status(operation, |status| status.pid = None);
+            return output.map_err(ProcessError::Io);
+        }
+        () = deadline => ProcessError::TimedOut(operation.timeout.unwrap_or_default()),
+        () = wait_for_cancellation(operation) => ProcessError::Cancelled,
+    };
+
+    set_operation_phase(operation, OperationPhase::Terminating);
+    if let Some(pid) = guard.pid {
+        signal_process_group(pid, ProcessSignal::Terminate);
+        if time::timeout(PROCESS_TERMINATE_GRACE, &mut output)
+            .await
+            .is_err()
+        {
+            signal_process_group(pid, ProcessSignal::Kill);
+            let _ = time::timeout(PROCESS_TERMINATE_GRACE, &mut output).await;
+        }
+    }
+    guard.pid = None;
+    update_operation_status(operation, |status| status.pid = None);
+    Err(stop_reason)
+}
+
+async fn run_rustic_command(
+    state: &AppState,
+    operation: &OperationContext,
+    args: Vec<String>,
+    env_vars: Vec<(String, String)>,
+    command_preview: Option<Vec<String>>,
+) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
+    run_rustic_command_with_stdin(
+        state,
+        operation,
+        args,
+        env_vars,
+        command_preview,
+        Stdio::null(),
+    )
+    .await
+}
+
+async fn run_rustic_command_with_stdin(
+    state: &AppState,
+    operation: &OperationContext,
+    args: Vec<String>,
+    env_vars: Vec<(String, String)>,
+    command_preview: Option<Vec<String>>,
+    stdin: Stdio,
+) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
+    let full_command = command_preview.unwrap_or_else(|| {
+        std::iter::once(state.rustic_bin.clone())
+            .chain(args.iter().cloned())
+            .collect::<Vec<_>>()
+    });
+    log_info(format!(
+        "executing rustic command: {}",
+        full_command.join(" ")
+    ));
+
+    let base = PathBuf::from(&state.state_dir);
+    let xdg_config_home = base.join("config");
+    let xdg_cache_home = base.join("cache");
+    let home_dir = base.join("home");
+    let rclone_config = xdg_config_home.join("rclone").join("rclone.conf");
+    let _ = fs::create_dir_all(home_dir.clone());
+    let _ = fs::create_dir_all(xdg_config_home.join("rclone"));
+    let _ = fs::create_dir_all(xdg_cache_home.clone());
+
+    if let Some(repository) = args
+        .iter()
+        .position(|arg| arg == "--repository")
+        .and_then(|index| args.get(index + 1))
+    {
+        let repository = redact_url_credentials(repository);
+        update_operation_status(operation, |status| status.repository = Some(repository));
+    }
+
+    let mut command = Command::new(&state.rustic_bin);
+    command.args(&args).stdin(stdin);
+    command.env("HOME", home_dir);
+    command.env("XDG_CONFIG_HOME", xdg_config_home);
+    command.env("XDG_CACHE_HOME", xdg_cache_home);
+    command.env("RCLONE_CONFIG", rclone_config);
+    command.env("RUSTIC_LOG_LEVEL", "warn");
+    for (key, value) in env_vars {
+        command.env(key, value);
+    }
+    let track_progress = args.iter().any(|arg| arg == "--progress-interval");
+    let output = run_child_process(command, operation, track_progress)
+        .await
+        .map_err(|error| match error {
+            ProcessError::TimedOut(timeout) => {
+                log_warn(format!(
+                    "rustic command timed out after {}s",
+                    timeout.as_secs()
+                ));
+                api_error(
+                    StatusCode::GATEWAY_TIMEOUT,
+                    format!("rustic command timed out after {}s", timeout.as_secs()),
+                )
+            }
+            ProcessError::Io(error) if error.kind() == std::io::ErrorKind::NotFound => api_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("rustic binary not found at '{}'", state.rustic_bin),
+            ),
+            ProcessError::Cancelled => {
+                log_warn(format!(
+                    "rustic command cancelled operation_id={}",
+                    operation.id
+                ));
+                api_error(StatusCode::CONFLICT, "operation cancelled")
+            }
+            ProcessError::Io(error) => api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to execute rustic: {error}"),
+            ),
+        })?;
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -616,6 +1990,7 @@ async fn run_rustic_command(
 
 async fn run_rclone_command(
     state: &AppState,
+    operation: &OperationContext,
     args: Vec<String>,
     command_preview: Vec<String>,
 ) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
@@ -624,42 +1999,49 @@ async fn run_rclone_command(
         command_preview.join(" ")
     ));
 
-    let state_dir = state.state_dir.clone();
-    let args_for_process = args.clone();
-    let output = tokio::task::spawn_blocking(move || {
-        let base = PathBuf::from(&state_dir);
-        let xdg_config_home = base.join("config");
-        let home_dir = base.join("home");
-        let rclone_config = xdg_config_home.join("rclone").join("rclone.conf");
-        let _ = fs::create_dir_all(home_dir.clone());
-        let _ = fs::create_dir_all(xdg_config_home.join("rclone"));
-
-        let mut command = Command::new("rclone");
-        command.args(&args_for_process);
-        command.env("HOME", home_dir);
-        command.env("XDG_CONFIG_HOME", xdg_config_home);
-        command.env("RCLONE_CONFIG", rclone_config);
-        command.output()
-    })
+    let base = PathBuf::from(&state.state_dir);
+    let xdg_config_home = base.join("config");
+    let home_dir = base.join("home");
+    let rclone_config = xdg_config_home.join("rclone").join("rclone.conf");
+    let _ = fs::create_dir_all(home_dir.clone());
+    let _ = fs::create_dir_all(xdg_config_home.join("rclone"));
+
+    let mut command = Command::new("rclone");
+    command.args(&args).stdin(Stdio::null());
+    command.env("HOME", home_dir);
+    command.env("XDG_CONFIG_HOME", xdg_config_home);
+    command.env("RCLONE_CONFIG", rclone_config);
+    let output =
+        run_child_process(command, operation, false)
             .await
-            .map_err(|error| {
-                api_error(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    format!("rclone command task failed: {error}"),
-                )
-            })?
-            .map_err(|error| {
-                if error.kind() == std::io::ErrorKind::NotFound {
+            .map_err(|error| match error {
+                ProcessError::TimedOut(timeout) => {
+                    log_warn(format!(
+                        "rclone command timed out after {}s",
+                        timeout.as_secs()
+                    ));
                     api_error(
-                        StatusCode::SERVICE_UNAVAILABLE,
-                        "rclone binary not found in PATH",
+                        StatusCode::GATEWAY_TIMEOUT,
+                        format!("rclone command timed out after {}s", timeout.as_secs()),
                     )
-                } else {
+                }
+                ProcessError::Io(error) if error.kind() == std::io::ErrorKind::NotFound => {
                     api_error(
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        format!("failed to execute rclone: {error}"),
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "rclone binary not found in PATH",
                     )
                 }
+                ProcessError::Cancelled => {
+                    log_warn(format!(
+                        "rclone command cancelled operation_id={}",
+                        operation.id
+                    ));
+                    api_error(StatusCode::CONFLICT, "operation cancelled")
+                }
+                ProcessError::Io(error) => api_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("failed to execute rclone: {error}"),
+                ),
             })?;
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
@@ -689,38 +2071,183 @@ async fn run_rclone_command(
     })
 }
 
-async fn prepare_repository_for_rclone(
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RepositoryAccess {
+    Exclusive,
+    Shared,
+}
+
+struct OperationSlot {
+    _repository: Option<OwnedMutexGuard<()>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+async fn acquire_operation_slot(
     state: &AppState,
-    mut repository: String,
-    backend: Option<&str>,
-    options: &HashMap<String, String>,
-    operation: &str,
-) -> Result<String, (StatusCode, Json<ApiErrorResponse>)> {
-    if backend != Some("rclone") {
-        if !options.is_empty() {
-            log_warn(format!(
-                "{operation} received repository options, but backend is not rclone; ignoring options"
-            ));
+    operation: &OperationContext,
+    repository: &str,
+    access: RepositoryAccess,
+) -> Result<OperationSlot, (StatusCode, Json<ApiErrorResponse>)> {
+    let repository_name = redact_url_credentials(repository);
+    update_operation_status(operation, |status| {
+        status.phase = OperationPhase::Queued;
+        status.repository = (!repository_name.is_empty()).then_some(repository_name);
+    });
+    tokio::select! {
+        biased;
+        () = wait_for_cancellation(operation) => {
+            Err(api_error(StatusCode::CONFLICT, "operation cancelled while queued"))
+        }
+        slot = wait_for_operation_slot(state, repository, access) => {
+            set_operation_phase(operation, OperationPhase::Running);
+            slot
         }
-        return Ok(repository);
     }
+}
+
+async fn wait_for_operation_slot(
+    state: &AppState,
+    repository: &str,
+    access: RepositoryAccess,
+) -> Result<OperationSlot, (StatusCode, Json<ApiErrorResponse>)> {
+    let repository_key = repository.trim().to_string();
+    let repository_name = redact_url_credentials(&repository_key);
+    let repository_guard = if access == RepositoryAccess::Exclusive {
+        let repository_lock = state
+            .repository_locks
+            .lock()
+            .map(|mut locks| {
+                locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+                locks.entry(repository_key.clone()).or_default().clone()
+            })
+            .map_err(|_| {
+                api_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to lock repository lock table",
+                )
+            })?;
+
+        Some(match repository_lock.clone().try_lock_owned() {
+            Ok(guard) => guard,
+            Err(_) => {
+                log_info(format!(
+                    "repository busy, queuing operation repository={repository_name}"
+                ));
+                repository_lock.lock_owned().await
+            }
+        })
+    } else {
+        None
+    };
+
+    let permit = match state.operation_slots.clone().try_acquire_owned() {
+        Ok(permit) => permit,
+        Err(_) => {
+            log_info(format!(
+                "all operation slots busy, queuing operation repository={repository_name}"
+            ));
+            state
+                .operation_slots
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|_| {
+                    api_error(
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "operation scheduler is shut down",
+                    )
+                })?
+        }
+    };
+
+    Ok(OperationSlot {
+        _repository: repository_guard,
+        _permit: permit,
+    })
+}
+
+fn redact_url_credentials(repository: &str) -> String {
+    let repository = repository.trim();
+    let repository = repository
+        .split_once('?')
+        .map_or(repository, |(base, _)| base);
+    let Some(scheme_end) = repository.find("://") else {
+        return repository.to_string();
+    };
+    let authority_start = scheme_end + 3;
+    let authority_end = repository[authority_start..]
+        .find('/')
+        .map_or(repository.len(), |offset| authority_start + offset);
+    match repository[authority_start..authority_end].rfind('@') {
+        Some(offset) => format!(
+            "{}***@{}",
+            &repository[..authority_start],
+            &repository[authority_start + offset + 1..]
+        ),
+        None => repository.to_string(),
+    }
+}
+
+async fn prepare_backup_repository(
+    state: &AppState,
+    operation: &OperationContext,
+    repository: String,
+    backend: Option<&str>,
+    options: &HashMap<String, String>,
+) -> Result<String, (StatusCode, Json<ApiErrorResponse>)> {
+    if backend == Some("rclone") {
+        let has_rclone_type = options
+            .get("rclone.type")
+            .or_else(|| options.get("rclone.config.type"))
+            .is_some_and(|value| !value.trim().is_empty());
+        if !has_rclone_type {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "rclone backup requires option `rclone.type` (example: rclone.type=s3)",
+            ));
+        }
+    } else if repository.starts_with("s3:") {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "s3 backend is not supported by this worker build; provide rclone backend/options or use a saved repository backup endpoint",
+        ));
+    }
+    prepare_repository_for_rclone(state, operation, repository, backend, options, "backup").await
+}
+
+async fn prepare_repository_for_rclone(
+    state: &AppState,
+    operation: &OperationContext,
+    mut repository: String,
+    backend: Option<&str>,
+    options: &HashMap<String, String>,
+    operation_name: &str,
+) -> Result<String, (StatusCode, Json<ApiErrorResponse>)> {
+    if backend != Some("rclone") {
+        if !options.is_empty() {
+            log_warn(format!(
+                "{operation_name} received repository options, but backend is not rclone; ignoring options"
+            ));
+        }
+        return Ok(repository);
+    }
+
+    let remote_from_repository = repository
+        .strip_prefix("rclone:")
+        .and_then(|value| value.split_once(':'))
+        .map(|(remote, _)| remote.to_string());
+    let remote_name = options
+        .get("rclone.remote")
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .or(remote_from_repository)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                "rclone backend requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
+            )
+        })?;
 
-    let remote_from_repository = repository
-        .strip_prefix("rclone:")
-        .and_then(|value| value.split_once(':'))
-        .map(|(remote, _)| remote.to_string());
-    let remote_name = options
-        .get("rclone.remote")
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
-        .or(remote_from_repository)
-        .ok_or_else(|| {
-            api_error(
-                StatusCode::BAD_REQUEST,
-                "rclone backend requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
-            )
-        })?;
-
     let rclone_type = options
         .get("rclone.type")
         .or_else(|| options.get("rclone.config.type"))
@@ -770,7 +2297,8 @@ async fn prepare_repository_for_rclone(
         rclone_args.push("--non-interactive".to_string());
         rclone_preview.push("--non-interactive".to_string());
 
-        let rclone_result = run_rclone_command(state, rclone_args, rclone_preview).await?;
+        let rclone_result =
+            run_rclone_command(state, operation, rclone_args, rclone_preview).await?;
         if !rclone_result.success {
             let reason = first_useful_error_line(&rclone_result.stderr)
                 .unwrap_or_else(|| "rclone config create failed".to_string());
@@ -786,7 +2314,7 @@ async fn prepare_repository_for_rclone(
         ));
     } else {
         log_info(format!(
-            "skipping rclone config create for {operation}; no rclone.type provided"
+            "skipping rclone config create for {operation_name}; no rclone.type provided"
         ));
     }
 
@@ -794,7 +2322,7 @@ async fn prepare_repository_for_rclone(
         let normalized_path = repository.trim_start_matches('/');
         repository = format!("rclone:{remote_name}:{normalized_path}");
         log_info(format!(
-            "normalized repository for rclone {operation}: {repository}"
+            "normalized repository for rclone {operation_name}: {repository}"
         ));
     }
 
@@ -803,9 +2331,18 @@ async fn prepare_repository_for_rclone(
 
 async fn rustic_version(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
 ) -> Result<Json<RusticVersionResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let _slot = acquire_operation_slot(&state, &operation, "", RepositoryAccess::Shared).await?;
     let worker = worker_runtime_stats(&state);
-    let rustic = run_rustic_command(&state, vec!["--version".to_string()], vec![], None).await?;
+    let rustic = run_rustic_command(
+        &state,
+        &operation,
+        vec!["--version".to_string()],
+        vec![],
+        None,
+    )
+    .await?;
     let version = rustic
         .stdout
         .lines()
@@ -823,10 +2360,13 @@ async fn rustic_version(
 
 async fn rustic_snapshots(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
 ) -> Result<Json<RusticSnapshotsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let _slot = acquire_operation_slot(&state, &operation, "", RepositoryAccess::Shared).await?;
     let worker = worker_runtime_stats(&state);
     let rustic = run_rustic_command(
         &state,
+        &operation,
         vec![
             "snapshots".to_string(),
             "--json".to_string(),
@@ -842,6 +2382,7 @@ async fn rustic_snapshots(
 
 async fn rustic_repository_snapshots(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
     Json(payload): Json<RusticRepositoryRequest>,
 ) -> Result<Json<RusticSnapshotsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
     let repository = payload.repository.trim();
@@ -851,8 +2392,11 @@ async fn rustic_repository_snapshots(
             "repository is required for snapshots",
         ));
     }
+    let _slot =
+        acquire_operation_slot(&state, &operation, repository, RepositoryAccess::Shared).await?;
     let repository = prepare_repository_for_rclone(
         &state,
+        &operation,
         repository.to_string(),
         payload.backend.as_deref(),
         &payload.options.unwrap_or_default(),
@@ -861,15 +2405,16 @@ async fn rustic_repository_snapshots(
     .await?;
 
     let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
-        }
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
     }
 
     let worker = worker_runtime_stats(&state);
     let rustic = run_rustic_command(
         &state,
+        &operation,
         vec![
             "--repository".to_string(),
             repository,
@@ -883,8 +2428,8 @@ async fn rustic_repository_snapshots(
     .await?;
 
     if !rustic.success {
-        let reason =
-            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| "failed to load snapshots".to_string());
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "failed to load snapshots".to_string());
         return Err(api_error(StatusCode::BAD_GATEWAY, reason));
     }
 
@@ -893,6 +2438,7 @@ async fn rustic_repository_snapshots(
 
 async fn rustic_snapshot_files(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
     Json(payload): Json<RusticSnapshotFilesRequest>,
 ) -> Result<Json<RusticSnapshotFilesResponse>, (StatusCode, Json<ApiErrorResponse>)> {
     let repository = payload.repository.trim();
@@ -909,8 +2455,11 @@ async fn rustic_snapshot_files(
             "snapshot is required for file listing",
         ));
     }
+    let _slot =
+        acquire_operation_slot(&state, &operation, repository, RepositoryAccess::Shared).await?;
     let repository = prepare_repository_for_rclone(
         &state,
+        &operation,
         repository.to_string(),
         payload.backend.as_deref(),
         &payload.options.unwrap_or_default(),
@@ -919,10 +2468,10 @@ async fn rustic_snapshot_files(
     .await?;
 
     let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
-        }
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
     }
 
     let mut target = snapshot.to_string();
@@ -943,7 +2492,7 @@ async fn rustic_snapshot_files(
     ];
 
     let worker = worker_runtime_stats(&state);
-    let rustic = run_rustic_command(&state, args, env_vars, None).await?;
+    let rustic = run_rustic_command(&state, &operation, args, env_vars, None).await?;
 
     if !rustic.success {
         let reason = first_useful_error_line(&rustic.stderr)
@@ -956,6 +2505,7 @@ async fn rustic_snapshot_files(
 
 async fn rustic_check(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
     Json(payload): Json<RusticRepositoryRequest>,
 ) -> Result<Json<RusticRepositoryCommandResponse>, (StatusCode, Json<ApiErrorResponse>)> {
     let repository = payload.repository.trim();
@@ -965,8 +2515,11 @@ async fn rustic_check(
             "repository is required for check",
         ));
     }
+    let _slot =
+        acquire_operation_slot(&state, &operation, repository, RepositoryAccess::Exclusive).await?;
     let repository = prepare_repository_for_rclone(
         &state,
+        &operation,
         repository.to_string(),
         payload.backend.as_deref(),
         &payload.options.unwrap_or_default(),
@@ -975,15 +2528,16 @@ async fn rustic_check(
     .await?;
 
     let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
-        }
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
     }
 
     let worker = worker_runtime_stats(&state);
     let rustic = run_rustic_command(
         &state,
+        &operation,
         vec![
             "--repository".to_string(),
             repository,
@@ -996,8 +2550,8 @@ async fn rustic_check(
     .await?;
 
     if !rustic.success {
-        let reason =
-            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| "repository check failed".to_string());
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "repository check failed".to_string());
         return Err(api_error(StatusCode::BAD_GATEWAY, reason));
     }
 
@@ -1006,6 +2560,7 @@ async fn rustic_check(
 
 async fn rustic_repair_index(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
     Json(payload): Json<RusticRepositoryRequest>,
 ) -> Result<Json<RusticRepositoryCommandResponse>, (StatusCode, Json<ApiErrorResponse>)> {
     let repository = payload.repository.trim();
@@ -1015,8 +2570,11 @@ async fn rustic_repair_index(
             "repository is required for repair index",
         ));
     }
+    let _slot =
+        acquire_operation_slot(&state, &operation, repository, RepositoryAccess::Exclusive).await?;
     let repository = prepare_repository_for_rclone(
         &state,
+        &operation,
         repository.to_string(),
         payload.backend.as_deref(),
         &payload.options.unwrap_or_default(),
@@ -1025,15 +2583,16 @@ async fn rustic_repair_index(
     .await?;
 
     let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
-        }
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
     }
 
     let worker = worker_runtime_stats(&state);
     let rustic = run_rustic_command(
         &state,
+        &operation,
         vec![
             "--repository".to_string(),
             repository,
@@ -1057,10 +2616,13 @@ async fn rustic_repair_index(
 
 async fn rustic_stats(
     State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
 ) -> Result<Json<RusticStatsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let _slot = acquire_operation_slot(&state, &operation, "", RepositoryAccess::Shared).await?;
     let worker = worker_runtime_stats(&state);
     let rustic = run_rustic_command(
         &state,
+        &operation,
         vec![
             "stats".to_string(),
             "--json".to_string(),
@@ -1074,660 +2636,2904 @@ async fn rustic_stats(
     Ok(Json(RusticStatsResponse { worker, rustic }))
 }
 
-async fn execute_backup_request(
-    state: &AppState,
-    payload: RusticBackupRequest,
-) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
-    let mut repository = payload.repository.trim().to_string();
-    if repository.is_empty() {
-        return Err(api_error(
-            StatusCode::BAD_REQUEST,
-            "repository is required for backup",
-        ));
+fn truncate_hook_output(output: &[u8]) -> String {
+    let start = output.len().saturating_sub(MAX_HOOK_OUTPUT_BYTES);
+    String::from_utf8_lossy(&output[start..]).to_string()
+}
+
+async fn run_backup_hook(
+    operation: &OperationContext,
+    stage: &'static str,
+    hook: &BackupHook,
+    backup_status: Option<&str>,
+) -> BackupHookResult {
+    let started = Instant::now();
+    let mut result = BackupHookResult {
+        stage,
+        command: hook.command.clone(),
+        success: false,
+        exit_code: None,
+        timed_out: false,
+        duration_ms: 0,
+        stdout: String::new(),
+        stderr: String::new(),
+    };
+    let Some((program, args)) = hook.command.split_first() else {
+        result.stderr = "hook command is empty".to_string();
+        return result;
+    };
+
+    let timeout_seconds = hook.timeout_seconds.unwrap_or(DEFAULT_HOOK_TIMEOUT_SECONDS);
+    let hook_operation = OperationContext {
+        timeout: (timeout_seconds > 0).then(|| Duration::from_secs(timeout_seconds)),
+        ..operation.clone()
+    };
+    let mut command = Command::new(program);
+    command.args(args).stdin(Stdio::null());
+    command.envs(&hook.env);
+    command.env("GLARE_HOOK_STAGE", stage);
+    command.env("GLARE_OPERATION_ID", &operation.id);
+    if let Some(backup_status) = backup_status {
+        command.env("GLARE_BACKUP_STATUS", backup_status);
     }
 
-    if payload.paths.is_empty() {
-        return Err(api_error(
-            StatusCode::BAD_REQUEST,
-            "backup requires at least one path",
-        ));
+    log_info(format!(
+        "running {stage}-backup hook: {}",
+        hook.command.join(" ")
+    ));
+    match run_child_process(command, &hook_operation, false).await {
+        Ok(output) => {
+            result.success = output.status.success();
+            result.exit_code = output.status.code();
+            result.stdout = truncate_hook_output(&output.stdout);
+            result.stderr = truncate_hook_output(&output.stderr);
+        }
+        Err(ProcessError::TimedOut(timeout)) => {
+            result.timed_out = true;
+            result.stderr = format!("hook timed out after {}s", timeout.as_secs());
+        }
+        Err(ProcessError::Cancelled) => {
+            result.stderr = "hook cancelled".to_string();
+        }
+        Err(ProcessError::Io(error)) => {
+            result.stderr = format!("failed to execute hook: {error}");
+        }
     }
-    if payload.paths.iter().any(|path| path.trim().is_empty()) {
-        return Err(api_error(
-            StatusCode::BAD_REQUEST,
-            "backup paths must not be empty",
+    result.duration_ms = started.elapsed().as_millis() as u64;
+    if !result.success {
+        log_warn(format!(
+            "{stage}-backup hook failed (exit={:?}): {}",
+            result.exit_code,
+            hook.command.join(" ")
         ));
     }
+    result
+}
 
-    let options = payload.options.unwrap_or_default();
-    if payload.backend.as_deref() == Some("rclone") {
-        let remote_from_repository = repository
-            .strip_prefix("rclone:")
-            .and_then(|value| value.split_once(':'))
-            .map(|(remote, _)| remote.to_string());
-        let remote_name = options
-            .get("rclone.remote")
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .or(remote_from_repository)
-            .ok_or_else(|| {
-                api_error(
-                    StatusCode::BAD_REQUEST,
-                    "rclone backup requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
-                )
-            })?;
-        let rclone_type = options
-            .get("rclone.type")
-            .or_else(|| options.get("rclone.config.type"))
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .ok_or_else(|| {
-                api_error(
-                    StatusCode::BAD_REQUEST,
-                    "rclone backup requires option `rclone.type` (example: rclone.type=s3)",
-                )
-            })?;
-
-        let mut rclone_args = vec![
-            "config".to_string(),
-            "create".to_string(),
-            remote_name.clone(),
-            rclone_type,
-        ];
-        let mut rclone_preview = vec![
-            "rclone".to_string(),
-            "config".to_string(),
-            "create".to_string(),
-            remote_name.clone(),
-            options
-                .get("rclone.type")
-                .or_else(|| options.get("rclone.config.type"))
-                .map(|value| value.trim().to_string())
-                .unwrap_or_default(),
-        ];
-
-        let mut rclone_config_items = options
-            .iter()
-            .filter_map(|(key, value)| {
-                key.strip_prefix("rclone.config.")
-                    .filter(|trimmed| !trimmed.is_empty() && *trimmed != "type")
-                    .map(|trimmed| (trimmed.to_string(), value.to_string()))
-            })
-            .collect::<Vec<_>>();
-        rclone_config_items.sort_by(|a, b| a.0.cmp(&b.0));
-
-        for (key, value) in rclone_config_items {
-            rclone_args.push(key.clone());
-            rclone_args.push(value.clone());
-            rclone_preview.push(key.clone());
-            rclone_preview.push(if is_sensitive_key(&key) {
-                "***".to_string()
-            } else {
-                value
-            });
+async fn run_backup_hooks(
+    operation: &OperationContext,
+    stage: &'static str,
+    hooks: &[BackupHook],
+    backup_status: Option<&str>,
+    results: &mut Vec<BackupHookResult>,
+) -> Option<(HookFailurePolicy, (StatusCode, Json<ApiErrorResponse>))> {
+    let mut failure = None;
+    for hook in hooks {
+        let result = run_backup_hook(operation, stage, hook, backup_status).await;
+        let success = result.success;
+        let timed_out = result.timed_out;
+        let reason = first_useful_error_line(&result.stderr)
+            .or_else(|| result.exit_code.map(|code| format!("exit code {code}")))
+            .unwrap_or_else(|| "hook failed".to_string());
+        results.push(result);
+        if success || hook.on_failure == HookFailurePolicy::Continue {
+            continue;
         }
-        rclone_args.push("--non-interactive".to_string());
-        rclone_preview.push("--non-interactive".to_string());
 
-        let rclone_result = run_rclone_command(state, rclone_args, rclone_preview).await?;
-        if !rclone_result.success {
-            let reason = first_useful_error_line(&rclone_result.stderr)
-                .unwrap_or_else(|| "rclone config create failed".to_string());
-            return Err(api_error(
-                StatusCode::BAD_GATEWAY,
-                format!("failed to create rclone config: {reason}"),
-            ));
+        let status = if operation_cancelled(operation) {
+            StatusCode::CONFLICT
+        } else {
+            StatusCode::FAILED_DEPENDENCY
+        };
+        let reason = if timed_out {
+            "timed out".to_string()
+        } else {
+            reason
+        };
+        let error = api_error(status, format!("{stage}-backup hook failed: {reason}"));
+        if stage == "post" && hook.on_failure == HookFailurePolicy::RunPostAnyway {
+            failure.get_or_insert((hook.on_failure, error));
+            continue;
         }
+        return Some((hook.on_failure, error));
+    }
+    failure
+}
 
-        if !repository.starts_with("rclone:") {
-            let normalized_path = repository.trim_start_matches('/');
-            repository = format!("rclone:{remote_name}:{normalized_path}");
-            log_info(format!(
-                "normalized repository for rclone backup: {repository}"
-            ));
-        }
-    } else if repository.starts_with("s3:") {
-        return Err(api_error(
-            StatusCode::BAD_REQUEST,
-            "s3 backend is not supported by this worker build; provide rclone backend/options or use a saved repository backup endpoint",
-        ));
-    } else if !options.is_empty() {
-        log_warn(
-            "backup received repository options, but backend is not rclone; ignoring options",
+async fn execute_backup_request(
+    state: &AppState,
+    operation: &OperationContext,
+    mut payload: RusticBackupRequest,
+) -> (
+    Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)>,
+    Vec<BackupHookResult>,
+) {
+    let hooks = std::mem::take(&mut payload.hooks);
+    if hooks.pre.iter().chain(&hooks.post).any(|hook| {
+        hook.command
+            .first()
+            .is_none_or(|program| program.trim().is_empty())
+    }) {
+        return (
+            Err(api_error(
+                StatusCode::BAD_REQUEST,
+                "backup hook command must not be empty",
+            )),
+            Vec::new(),
         );
     }
+    if let Err(reason) =
+        backup_filter_args(&payload).and_then(|_| backup_snapshot_args(state, &payload))
+    {
+        return (Err(api_error(StatusCode::BAD_REQUEST, reason)), Vec::new());
+    }
 
-    let mut args = vec![
-        "--repository".to_string(),
-        repository,
-        "backup".to_string(),
-        "--json".to_string(),
-        "--no-progress".to_string(),
-    ];
+    let mut results = Vec::new();
+    let (result, run_post) =
+        match run_backup_hooks(operation, "pre", &hooks.pre, None, &mut results).await {
+            None => (run_backup_command(state, operation, payload).await, true),
+            Some((policy, error)) => (Err(error), policy == HookFailurePolicy::RunPostAnyway),
+        };
+    if !run_post || hooks.post.is_empty() {
+        return (result, results);
+    }
 
-    if payload.dry_run.unwrap_or(false) {
-        args.push("--dry-run".to_string());
+    let backup_status = if result.is_ok() { "success" } else { "failed" };
+    let post_failure = run_backup_hooks(
+        operation,
+        "post",
+        &hooks.post,
+        Some(backup_status),
+        &mut results,
+    )
+    .await;
+    match (result, post_failure) {
+        (Ok(_), Some((_, error))) => (Err(error), results),
+        (result, _) => (result, results),
     }
+}
 
-    if let Some(tags) = payload.tags {
-        for tag in tags {
-            if !tag.trim().is_empty() {
-                args.push("--tag".to_string());
-                args.push(tag);
-            }
+fn dump_filename(name: Option<&String>, fallback: &str, extension: &str) -> String {
+    match name.map(|name| name.trim()).filter(|name| !name.is_empty()) {
+        Some(name) => name.to_string(),
+        None => {
+            let base = fallback
+                .split(['?', '#'])
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches('/')
+                .rsplit(['/', '\\'])
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .map(|ch| {
+                    if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_') {
+                        ch
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>();
+            let base = if base.is_empty() { "database" } else { &base };
+            format!("{base}.{extension}")
         }
     }
+}
 
-    args.extend(payload.paths);
-
+fn dump_source_command(source: &BackupSource) -> Result<DumpCommand, String> {
+    let mut args = Vec::new();
+    let mut preview = Vec::new();
     let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    let mut push_arg = |flag: &str, value: Option<String>| {
+        if let Some(value) = value.map(|value| value.trim().to_string())
+            && !value.is_empty()
+        {
+            args.push(flag.to_string());
+            args.push(value.clone());
+            preview.push(flag.to_string());
+            preview.push(redact_url_credentials(&value));
         }
-    }
+    };
 
-    let rustic = run_rustic_command(state, args, env_vars, None).await?;
-    if !rustic.success {
-        let reason =
-            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| "backup command failed".to_string());
-        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
-    }
+    let (tool, filename) = match source {
+        BackupSource::Postgres {
+            host,
+            port,
+            username,
+            password,
+            database,
+            filename,
+        } => {
+            if database.trim().is_empty() {
+                return Err("postgres source requires a database".to_string());
+            }
+            push_arg("--host", host.clone());
+            push_arg("--port", port.map(|port| port.to_string()));
+            push_arg("--username", username.clone());
+            push_arg("--dbname", Some(database.clone()));
+            if let Some(password) = password {
+                env_vars.push(("PGPASSWORD".to_string(), password.clone()));
+            }
+            args.extend(["--format=plain".to_string(), "--no-password".to_string()]);
+            preview.extend(["--format=plain".to_string(), "--no-password".to_string()]);
+            ("pg_dump", dump_filename(filename.as_ref(), database, "sql"))
+        }
+        BackupSource::Mysql {
+            host,
+            port,
+            username,
+            password,
+            database,
+            filename,
+        } => {
+            if database.trim().is_empty() {
+                return Err("mysql source requires a database".to_string());
+            }
+            push_arg("--host", host.clone());
+            push_arg("--port", port.map(|port| port.to_string()));
+            push_arg("--user", username.clone());
+            if let Some(password) = password {
+                env_vars.push(("MYSQL_PWD".to_string(), password.clone()));
+            }
+            let flags = [
+                "--single-transaction",
+                "--quick",
+                "--routines",
+                "--triggers",
+            ];
+            args.extend(flags.iter().map(|flag| flag.to_string()));
+            preview.extend(flags.iter().map(|flag| flag.to_string()));
+            args.push(database.trim().to_string());
+            preview.push(database.trim().to_string());
+            (
+                "mysqldump",
+                dump_filename(filename.as_ref(), database, "sql"),
+            )
+        }
+        BackupSource::Sqlite { path, filename } => {
+            if path.trim().is_empty() {
+                return Err("sqlite source requires a path".to_string());
+            }
+            let dump_args = [
+                "-readonly".to_string(),
+                path.trim().to_string(),
+                ".dump".to_string(),
+            ];
+            args.extend(dump_args.iter().cloned());
+            preview.extend(dump_args);
+            ("sqlite3", dump_filename(filename.as_ref(), path, "sql"))
+        }
+    };
 
-    Ok(rustic)
+    Ok(DumpCommand {
+        tool,
+        preview: std::iter::once(tool.to_string()).chain(preview).collect(),
+        args,
+        env_vars,
+        filename,
+    })
 }
 
-async fn rustic_backup(
-    State(state): State<AppState>,
-    Json(payload): Json<RusticBackupRequest>,
-) -> Result<Json<RusticBackupResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let worker = worker_runtime_stats(&state);
-    let rustic = execute_backup_request(&state, payload).await?;
+async fn run_backup_from_dump(
+    state: &AppState,
+    operation: &OperationContext,
+    dump: DumpCommand,
+    args: Vec<String>,
+    env_vars: Vec<(String, String)>,
+) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
+    log_info(format!(
+        "streaming database dump into backup: {}",
+        dump.preview.join(" ")
+    ));
+    let mut command = Command::new(dump.tool);
+    command
+        .args(&dump.args)
+        .envs(dump.env_vars)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    #[cfg(unix)]
+    command.process_group(0);
+
+    let mut child = command.spawn().map_err(|error| {
+        if error.kind() == std::io::ErrorKind::NotFound {
+            api_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("{} not found on this worker", dump.tool),
+            )
+        } else {
+            api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to execute {}: {error}", dump.tool),
+            )
+        }
+    })?;
+    let mut guard = ProcessGroupGuard { pid: child.id() };
+    let dump_stdout: Stdio = child
+        .stdout
+        .take()
+        .and_then(|stdout| stdout.try_into().ok())
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to capture {} output", dump.tool),
+            )
+        })?;
+    let mut dump_stderr = child.stderr.take();
+    let stderr_reader = tokio::spawn(async move {
+        let mut output = Vec::new();
+        if let Some(stderr) = dump_stderr.as_mut() {
+            let _ = stderr.read_to_end(&mut output).await;
+        }
+        String::from_utf8_lossy(&output).to_string()
+    });
 
-    Ok(Json(RusticBackupResponse { worker, rustic }))
-}
+    let repository = args
+        .iter()
+        .position(|arg| arg == "--repository")
+        .and_then(|index| args.get(index + 1))
+        .cloned();
+    let rustic =
+        run_rustic_command_with_stdin(state, operation, args, env_vars.clone(), None, dump_stdout)
+            .await;
 
-async fn rustic_forget(
-    State(state): State<AppState>,
-    Json(payload): Json<RusticForgetRequest>,
-) -> Result<Json<RusticForgetResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let mut repository = payload.repository.trim().to_string();
-    if repository.is_empty() {
-        return Err(api_error(
-            StatusCode::BAD_REQUEST,
-            "repository is required for forget",
-        ));
+    let dump_status = match time::timeout(PROCESS_TERMINATE_GRACE, child.wait()).await {
+        Ok(status) => status.ok(),
+        Err(_) => {
+            if let Some(pid) = guard.pid {
+                signal_process_group(pid, ProcessSignal::Kill);
+            }
+            child.wait().await.ok()
+        }
+    };
+    guard.pid = None;
+    let dump_stderr = stderr_reader.await.unwrap_or_default();
+    let rustic = rustic?;
+    if dump_status.is_some_and(|status| status.success()) {
+        return Ok(rustic);
     }
 
-    let options = payload.options.unwrap_or_default();
-    if payload.backend.as_deref() == Some("rclone") {
-        let remote_from_repository = repository
-            .strip_prefix("rclone:")
-            .and_then(|value| value.split_once(':'))
-            .map(|(remote, _)| remote.to_string());
-        let remote_name = options
-            .get("rclone.remote")
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .or(remote_from_repository)
-            .ok_or_else(|| {
-                api_error(
-                    StatusCode::BAD_REQUEST,
-                    "rclone backend requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
-                )
-            })?;
-        let rclone_type = options
-            .get("rclone.type")
-            .or_else(|| options.get("rclone.config.type"))
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .ok_or_else(|| {
-                api_error(
-                    StatusCode::BAD_REQUEST,
-                    "rclone backend requires option `rclone.type` (example: rclone.type=s3)",
-                )
-            })?;
+    let reason = first_useful_error_line(&dump_stderr).unwrap_or_else(|| match dump_status {
+        Some(status) => format!("exit status {status}"),
+        None => "process did not exit".to_string(),
+    });
+    if rustic.success
+        && let (Some(snapshot_id), _) = extract_snapshot_ref(rustic.parsed_json.as_ref())
+        && let Some(repository) = repository
+    {
+        log_warn(format!(
+            "{} failed, removing incomplete snapshot {snapshot_id}",
+            dump.tool
+        ));
+        let forget = run_rustic_command(
+            state,
+            operation,
+            vec![
+                "--repository".to_string(),
+                repository,
+                "forget".to_string(),
+                snapshot_id,
+            ],
+            env_vars,
+            None,
+        )
+        .await;
+        if !forget.is_ok_and(|forget| forget.success) {
+            log_warn("failed to remove incomplete dump snapshot");
+        }
+    }
+    Err(api_error(
+        StatusCode::BAD_GATEWAY,
+        format!("{} failed: {reason}", dump.tool),
+    ))
+}
 
-        let mut rclone_args = vec![
-            "config".to_string(),
-            "create".to_string(),
-            remote_name.clone(),
-            rclone_type,
-        ];
-        let mut rclone_preview = vec![
-            "rclone".to_string(),
-            "config".to_string(),
-            "create".to_string(),
-            remote_name.clone(),
-            options
-                .get("rclone.type")
-                .or_else(|| options.get("rclone.config.type"))
-                .map(|value| value.trim().to_string())
-                .unwrap_or_default(),
-        ];
+fn parse_size_limit(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let number_end = value
+        .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(number_end);
+    let number = number.parse::<f64>().ok()?;
+    let multiplier = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "k" | "kb" => 1e3,
+        "m" | "mb" => 1e6,
+        "g" | "gb" => 1e9,
+        "t" | "tb" => 1e12,
+        "ki" | "kib" => 1024.0,
+        "mi" | "mib" => 1024.0 * 1024.0,
+        "gi" | "gib" => 1024.0 * 1024.0 * 1024.0,
+        "ti" | "tib" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    let bytes = number * multiplier;
+    (bytes.is_finite() && bytes >= 1.0).then_some(bytes as u64)
+}
 
-        let mut rclone_config_items = options
-            .iter()
-            .filter_map(|(key, value)| {
-                key.strip_prefix("rclone.config.")
-                    .filter(|trimmed| !trimmed.is_empty() && *trimmed != "type")
-                    .map(|trimmed| (trimmed.to_string(), value.to_string()))
-            })
-            .collect::<Vec<_>>();
-        rclone_config_items.sort_by(|a, b| a.0.cmp(&b.0));
+fn backup_filter_args(payload: &RusticBackupRequest) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    for (flag, field, patterns) in [
+        ("--glob", "globs", &payload.globs),
+        ("--iglob", "iglobs", &payload.iglobs),
+    ] {
+        for pattern in patterns {
+            if pattern.trim().is_empty() {
+                return Err(format!("{field} must not contain empty patterns"));
+            }
+            if pattern.chars().any(char::is_control) {
+                return Err(format!(
+                    "{field} pattern '{}' must not contain control characters",
+                    pattern.escape_default()
+                ));
+            }
+            args.push(flag.to_string());
+            args.push(pattern.clone());
+        }
+    }
 
-        for (key, value) in rclone_config_items {
-            rclone_args.push(key.clone());
-            rclone_args.push(value.clone());
-            rclone_preview.push(key.clone());
-            rclone_preview.push(if is_sensitive_key(&key) {
-                "***".to_string()
-            } else {
-                value
-            });
+    for (flag, field, files) in [
+        ("--glob-file", "globFiles", &payload.glob_files),
+        ("--iglob-file", "iglobFiles", &payload.iglob_files),
+    ] {
+        for file in files {
+            let file = file.trim();
+            if file.is_empty() {
+                return Err(format!("{field} must not contain empty paths"));
+            }
+            if !PathBuf::from(file).is_file() {
+                return Err(format!(
+                    "{field} entry '{file}' is not a file on this worker"
+                ));
+            }
+            args.push(flag.to_string());
+            args.push(file.to_string());
         }
-        rclone_args.push("--non-interactive".to_string());
-        rclone_preview.push("--non-interactive".to_string());
+    }
 
-        let rclone_result = run_rclone_command(&state, rclone_args, rclone_preview).await?;
-        if !rclone_result.success {
-            let reason = first_useful_error_line(&rclone_result.stderr)
-                .unwrap_or_else(|| "rclone config create failed".to_string());
-            return Err(api_error(
-                StatusCode::BAD_GATEWAY,
-                format!("failed to create rclone config: {reason}"),
+    for name in &payload.exclude_if_present {
+        let name = name.trim();
+        if name.is_empty() || name.contains(['/', '\\']) {
+            return Err(format!(
+                "excludeIfPresent entry '{name}' must be a file name without directories"
             ));
         }
+        args.push("--exclude-if-present".to_string());
+        args.push(name.to_string());
+    }
 
-        if !repository.starts_with("rclone:") {
-            let normalized_path = repository.trim_start_matches('/');
-            repository = format!("rclone:{remote_name}:{normalized_path}");
-            log_info(format!(
-                "normalized repository for rclone forget: {repository}"
+    if let Some(limit) = payload.exclude_larger_than.as_deref() {
+        if parse_size_limit(limit).is_none() {
+            return Err(format!(
+                "excludeLargerThan '{limit}' must be a size such as 500M, 2GiB or 1048576"
             ));
         }
+        args.push("--exclude-larger-than".to_string());
+        args.push(limit.trim().replace(' ', ""));
     }
 
-    let mut args = vec![
-        "--repository".to_string(),
-        repository,
-        "forget".to_string(),
-        "--json".to_string(),
-        "--no-progress".to_string(),
-    ];
-
-    if let Some(n) = payload.keep_last {
-        args.push("--keep-last".to_string());
-        args.push(n.to_string());
-    }
-    if let Some(n) = payload.keep_daily {
-        args.push("--keep-daily".to_string());
-        args.push(n.to_string());
+    if payload.no_require_git && !payload.git_ignore {
+        return Err("noRequireGit requires gitIgnore".to_string());
     }
-    if let Some(n) = payload.keep_weekly {
-        args.push("--keep-weekly".to_string());
-        args.push(n.to_string());
+    if payload.git_ignore {
+        args.push("--git-ignore".to_string());
     }
-    if let Some(n) = payload.keep_monthly {
-        args.push("--keep-monthly".to_string());
-        args.push(n.to_string());
+    if payload.no_require_git {
+        args.push("--no-require-git".to_string());
     }
-    if let Some(n) = payload.keep_yearly {
-        args.push("--keep-yearly".to_string());
-        args.push(n.to_string());
+    if payload.one_file_system {
+        args.push("--one-file-system".to_string());
     }
-    if let Some(ref duration) = payload.keep_within {
-        let trimmed = duration.trim();
-        if !trimmed.is_empty() {
-            args.push("--keep-within".to_string());
-            args.push(trimmed.to_string());
+    Ok(args)
+}
+
+fn worker_id_from_token(token: &str) -> Option<String> {
+    let (encoded, _) = token.split_once(':')?;
+    let mut bits = 0u32;
+    let mut value = 0u32;
+    let mut bytes = Vec::with_capacity(16);
+    for ch in encoded.chars() {
+        let index = BASE32_ALPHABET.find(ch.to_ascii_uppercase())? as u32;
+        value = (value << 5) | index;
+        bits += 5;
+        if bits >= 8 {
+            bytes.push(((value >> (bits - 8)) & 0xff) as u8);
+            bits -= 8;
         }
     }
-    if payload.prune == Some(true) {
-        args.push("--prune".to_string());
+    if bytes.len() != 16 {
+        return None;
     }
-    if payload.dry_run == Some(true) {
-        args.push("--dry-run".to_string());
+
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    ))
+}
+
+fn validate_backup_host(host: &str) -> Result<(), String> {
+    let host = host.trim();
+    if host.is_empty() || host.len() > 255 {
+        return Err("host must be between 1 and 255 characters".to_string());
+    }
+    if host.chars().any(|ch| ch.is_whitespace() || ch.is_control()) {
+        return Err("host must not contain whitespace".to_string());
+    }
+    Ok(())
+}
+
+fn backup_snapshot_args(
+    state: &AppState,
+    payload: &RusticBackupRequest,
+) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let host = payload
+        .host
+        .as_deref()
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+        .or(state.default_backup_host.as_deref());
+    if let Some(host) = host {
+        validate_backup_host(host)?;
+        args.push("--host".to_string());
+        args.push(host.to_string());
     }
 
-    let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    if let Some(label) = payload.label.as_deref().map(str::trim) {
+        if label.is_empty() || label.len() > 255 || label.chars().any(char::is_control) {
+            return Err("label must be 1-255 characters without control characters".to_string());
         }
+        args.push("--label".to_string());
+        args.push(label.to_string());
     }
 
-    let worker = worker_runtime_stats(&state);
-    let rustic = run_rustic_command(&state, args, env_vars, None).await?;
+    if let Some(description) = payload.description.as_deref() {
+        if description.trim().is_empty() || description.len() > 4096 || description.contains('\0') {
+            return Err("description must be 1-4096 characters".to_string());
+        }
+        args.push("--description".to_string());
+        args.push(description.to_string());
+    }
 
-    if !rustic.success {
-        let reason =
-            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| "forget command failed".to_string());
-        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    if let Some(parent) = payload.parent.as_deref().map(str::trim) {
+        if parent.is_empty()
+            || parent.len() > 64
+            || !parent.chars().all(|ch| ch.is_ascii_hexdigit())
+        {
+            return Err("parent must be a snapshot id (hex)".to_string());
+        }
+        if payload.force {
+            return Err("parent cannot be combined with force".to_string());
+        }
+        args.push("--parent".to_string());
+        args.push(parent.to_string());
     }
 
-    Ok(Json(RusticForgetResponse { worker, rustic }))
+    if payload.force {
+        if payload.ignore_ctime || payload.ignore_inode {
+            return Err("ignoreCtime and ignoreInode have no effect with force".to_string());
+        }
+        args.push("--force".to_string());
+    }
+    if payload.ignore_ctime {
+        args.push("--ignore-ctime".to_string());
+    }
+    if payload.ignore_inode {
+        args.push("--ignore-inode".to_string());
+    }
+    Ok(args)
 }
 
-async fn rustic_restore(
-    State(state): State<AppState>,
-    Json(payload): Json<RusticRestoreRequest>,
-) -> Result<Json<RusticRepositoryCommandResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let repository = payload.repository.trim();
-    let snapshot = payload.snapshot.trim();
-    let target = payload.target.trim();
+async fn run_backup_command(
+    state: &AppState,
+    operation: &OperationContext,
+    payload: RusticBackupRequest,
+) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
+    let repository = payload.repository.trim().to_string();
     if repository.is_empty() {
         return Err(api_error(
             StatusCode::BAD_REQUEST,
-            "repository is required for restore",
+            "repository is required for backup",
         ));
     }
-    if snapshot.is_empty() {
+
+    let dump = payload
+        .source
+        .as_ref()
+        .map(dump_source_command)
+        .transpose()
+        .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+    if dump.is_some() && !payload.paths.is_empty() {
         return Err(api_error(
             StatusCode::BAD_REQUEST,
-            "snapshot is required for restore",
+            "backup paths cannot be combined with a database source",
         ));
     }
-    if target.is_empty() {
+    if dump.is_none() && payload.paths.is_empty() {
         return Err(api_error(
             StatusCode::BAD_REQUEST,
-            "target is required for restore",
+            "backup requires at least one path",
+        ));
+    }
+    if payload.paths.iter().any(|path| path.trim().is_empty()) {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "backup paths must not be empty",
+        ));
+    }
+    let filter_args = backup_filter_args(&payload)
+        .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+    let snapshot_args = backup_snapshot_args(state, &payload)
+        .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+    if dump.is_some() && !filter_args.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "exclusion and filesystem options cannot be combined with a database source",
         ));
     }
 
-    let repository = prepare_repository_for_rclone(
-        &state,
-        repository.to_string(),
+    let options = payload.options.unwrap_or_default();
+    let repository = prepare_backup_repository(
+        state,
+        operation,
+        repository,
         payload.backend.as_deref(),
-        &payload.options.unwrap_or_default(),
-        "restore",
+        &options,
     )
     .await?;
 
-    let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
-        }
-    }
-
     let mut args = vec![
         "--repository".to_string(),
         repository,
-        "restore".to_string(),
-        "--no-progress".to_string(),
-        "--target".to_string(),
-        target.to_string(),
-        snapshot.to_string(),
+        "backup".to_string(),
+        "--json".to_string(),
+        "--progress-interval".to_string(),
+        PROGRESS_INTERVAL.to_string(),
     ];
-    if let Some(path) = payload.path {
-        let trimmed = path.trim();
-        if !trimmed.is_empty() {
-            args.push(trimmed.to_string());
-        }
-    }
-    if payload.dry_run == Some(true) {
+
+    if payload.dry_run.unwrap_or(false) {
         args.push("--dry-run".to_string());
     }
 
-    let worker = worker_runtime_stats(&state);
-    let rustic = run_rustic_command(&state, args, env_vars, None).await?;
+    if let Some(tags) = payload.tags {
+        for tag in tags {
+            if !tag.trim().is_empty() {
+                args.push("--tag".to_string());
+                args.push(tag);
+            }
+        }
+    }
+    args.extend(snapshot_args);
+    args.extend(filter_args);
+
+    match &dump {
+        Some(dump) => {
+            args.push("--stdin-filename".to_string());
+            args.push(dump.filename.clone());
+            args.push("-".to_string());
+        }
+        None => args.extend(payload.paths),
+    }
+
+    let mut env_vars = Vec::new();
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    }
+
+    let rustic = match dump {
+        Some(dump) => run_backup_from_dump(state, operation, dump, args, env_vars).await?,
+        None => run_rustic_command(state, operation, args, env_vars, None).await?,
+    };
     if !rustic.success {
-        let reason =
-            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| "restore command failed".to_string());
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "backup command failed".to_string());
         return Err(api_error(StatusCode::BAD_GATEWAY, reason));
     }
 
-    Ok(Json(RusticRepositoryCommandResponse { worker, rustic }))
+    Ok(rustic)
 }
 
-async fn ls_dirs(
-    Json(payload): Json<LsDirsRequest>,
-) -> Result<Json<LsDirsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let path = payload
-        .path
-        .as_deref()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .unwrap_or("/")
-        .to_string();
+async fn rustic_backup(
+    State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
+    Json(payload): Json<RusticBackupRequest>,
+) -> Result<Json<RusticBackupResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let _slot = acquire_operation_slot(
+        &state,
+        &operation,
+        &payload.repository,
+        RepositoryAccess::Exclusive,
+    )
+    .await?;
+    let worker = worker_runtime_stats(&state);
+    let (result, hooks) = execute_backup_request(&state, &operation, payload).await;
+    let rustic = result?;
 
-    let mut dirs = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(&path) {
-        for entry in entries.flatten() {
-            if let Ok(file_type) = entry.file_type() {
-                if file_type.is_dir() {
-                    if let Some(name) = entry.file_name().to_str() {
-                        if !name.starts_with('.') {
-                            dirs.push(format!("{}/{}", path.trim_end_matches('/'), name));
-                        }
-                    }
-                }
-            }
+    Ok(Json(RusticBackupResponse {
+        worker,
+        rustic,
+        hooks,
+    }))
+}
+
+async fn forward_request_body(body: Body, chunks: &mpsc::Sender<Bytes>) -> Result<u64, String> {
+    let mut received = 0u64;
+    let mut frames = body.into_data_stream();
+    while let Some(frame) = frames.next().await {
+        let chunk = frame.map_err(|error| error.to_string())?;
+        received += chunk.len() as u64;
+        if chunks.send(chunk).await.is_err() {
+            break;
         }
     }
-    dirs.sort();
-    Ok(Json(LsDirsResponse { dirs }))
+    Ok(received)
 }
 
-async fn rustic_init(
+async fn rustic_backup_stream(
     State(state): State<AppState>,
-    Json(payload): Json<RusticInitRequest>,
-) -> Result<Json<RusticInitResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let mut repository = payload.repository.trim().to_string();
-    log_info(format!("received init request for repository={repository}"));
-    if repository.is_empty() {
+    Extension(operation): Extension<OperationContext>,
+    Query(query): Query<RusticBackupStreamQuery>,
+    headers: HeaderMap,
+    body: Body,
+) -> Result<Json<RusticBackupStreamResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let header_value = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    };
+    let repository = header_value(REPOSITORY_HEADER)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                "X-Repository header is required for backup-stream",
+            )
+        })?
+        .to_string();
+    let backend = header_value(REPOSITORY_BACKEND_HEADER);
+    let options = header_value(REPOSITORY_OPTIONS_HEADER)
+        .map(serde_json::from_str::<HashMap<String, String>>)
+        .transpose()
+        .map_err(|_| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                "X-Repository-Options must be a JSON object of strings",
+            )
+        })?
+        .unwrap_or_default();
+    let filename = query.filename.trim().to_string();
+    if filename.is_empty() || filename.chars().any(char::is_control) {
         return Err(api_error(
             StatusCode::BAD_REQUEST,
-            "repository is required for init",
+            "filename is required and must not contain control characters",
         ));
     }
 
-    let options = payload.options.unwrap_or_default();
-    if payload.backend.as_deref() == Some("rclone") {
-        let remote_from_repository = repository
-            .strip_prefix("rclone:")
-            .and_then(|value| value.split_once(':'))
-            .map(|(remote, _)| remote.to_string());
-        let remote_name = options
-            .get("rclone.remote")
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .or(remote_from_repository)
-            .ok_or_else(|| {
-                api_error(
-                    StatusCode::BAD_REQUEST,
-                    "rclone backend requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
-                )
-            })?;
-        let rclone_type = options
-            .get("rclone.type")
-            .or_else(|| options.get("rclone.config.type"))
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .ok_or_else(|| {
-                api_error(
-                    StatusCode::BAD_REQUEST,
-                    "rclone backend requires option `rclone.type` (example: rclone.type=s3)",
-                )
-            })?;
+    let _slot =
+        acquire_operation_slot(&state, &operation, &repository, RepositoryAccess::Exclusive)
+            .await?;
+    let repository =
+        prepare_backup_repository(&state, &operation, repository, backend, &options).await?;
 
-        let mut rclone_args = vec![
-            "config".to_string(),
-            "create".to_string(),
-            remote_name.clone(),
-            rclone_type,
-        ];
-        let mut rclone_preview = vec![
-            "rclone".to_string(),
-            "config".to_string(),
-            "create".to_string(),
-            remote_name.clone(),
-            options
-                .get("rclone.type")
-                .or_else(|| options.get("rclone.config.type"))
-                .map(|value| value.trim().to_string())
-                .unwrap_or_default(),
-        ];
+    let mut args = vec![
+        "--repository".to_string(),
+        repository.clone(),
+        "backup".to_string(),
+        "--json".to_string(),
+        "--progress-interval".to_string(),
+        PROGRESS_INTERVAL.to_string(),
+        "--stdin-filename".to_string(),
+        filename,
+    ];
+    if let Some(host) = &state.default_backup_host {
+        args.push("--host".to_string());
+        args.push(host.clone());
+    }
+    if query.dry_run.unwrap_or(false) {
+        args.push("--dry-run".to_string());
+    }
+    for tag in query
+        .tags
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+    {
+        args.push("--tag".to_string());
+        args.push(tag.to_string());
+    }
+    args.push("-".to_string());
 
-        let mut rclone_config_items = options
-            .iter()
-            .filter_map(|(key, value)| {
-                key.strip_prefix("rclone.config.")
-                    .filter(|trimmed| !trimmed.is_empty() && *trimmed != "type")
-                    .map(|trimmed| (trimmed.to_string(), value.to_string()))
-            })
-            .collect::<Vec<_>>();
-        rclone_config_items.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut env_vars = Vec::new();
+    if let Some(password) = headers
+        .get(REPOSITORY_PASSWORD_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.trim().is_empty())
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password.to_string()));
+    }
 
-        for (key, value) in rclone_config_items {
-            rclone_args.push(key.clone());
-            rclone_args.push(value.clone());
-            rclone_preview.push(key.clone());
-            rclone_preview.push(if is_sensitive_key(&key) {
-                "***".to_string()
-            } else {
-                value
-            });
+    let worker = worker_runtime_stats(&state);
+    let (reader, mut writer) = std::io::pipe().map_err(|error| {
+        api_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("failed to create backup stream pipe: {error}"),
+        )
+    })?;
+    let (chunks, mut pending_chunks) = mpsc::channel::<Bytes>(BACKUP_STREAM_BUFFER_CHUNKS);
+    tokio::task::spawn_blocking(move || {
+        while let Some(chunk) = pending_chunks.blocking_recv() {
+            if writer.write_all(&chunk).is_err() {
+                break;
+            }
         }
-        rclone_args.push("--non-interactive".to_string());
-        rclone_preview.push("--non-interactive".to_string());
+    });
 
-        let rclone_result = run_rclone_command(&state, rclone_args, rclone_preview).await?;
-        if !rclone_result.success {
-            let reason = first_useful_error_line(&rclone_result.stderr)
-                .unwrap_or_else(|| "rclone config create failed".to_string());
-            return Err(api_error(
-                StatusCode::BAD_GATEWAY,
-                format!("failed to create rclone config: {reason}"),
-            ));
+    let rustic = run_rustic_command_with_stdin(
+        &state,
+        &operation,
+        args,
+        env_vars,
+        None,
+        Stdio::from(reader),
+    );
+    tokio::pin!(rustic);
+    let forwarded = tokio::select! {
+        rustic = &mut rustic => Err(rustic),
+        forwarded = forward_request_body(body, &chunks) => Ok(forwarded),
+    };
+    let (rustic, bytes_received) = match forwarded {
+        Ok(Ok(bytes_received)) => {
+            drop(chunks);
+            (rustic.await?, bytes_received)
         }
-
-        if !repository.starts_with("rclone:") {
-            let normalized_path = repository.trim_start_matches('/');
-            repository = format!("rclone:{remote_name}:{normalized_path}");
-            log_info(format!(
-                "normalized repository for rclone backend: {repository}"
+        Ok(Err(reason)) => {
+            log_warn(format!(
+                "backup stream aborted while reading request body: {reason}"
+            ));
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                format!("failed to read request body: {reason}"),
             ));
         }
-    } else if !options.is_empty() {
-        log_warn("init received repository options, but this rustic build does not support --option; ignoring options");
+        Err(rustic) => (rustic?, 0),
+    };
+    if !rustic.success {
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "backup command failed".to_string());
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
     }
 
-    let mut args = vec!["--repository".to_string(), repository];
-    let mut display_args = args.clone();
-    args.push("init".to_string());
-    args.push("--no-progress".to_string());
-    display_args.push("init".to_string());
-    display_args.push("--no-progress".to_string());
+    let (snapshot_id, snapshot_time) = extract_snapshot_ref(rustic.parsed_json.as_ref());
+    Ok(Json(RusticBackupStreamResponse {
+        worker,
+        snapshot_id,
+        snapshot_time,
+        bytes_received,
+        rustic,
+    }))
+}
 
-    let mut env_vars = Vec::new();
-    if let Some(password) = payload.password {
-        if !password.trim().is_empty() {
-            env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+fn retention_keep_args(policy: &RetentionPolicy) -> Vec<String> {
+    let mut args = Vec::new();
+    for (flag, value) in [
+        ("--keep-last", policy.keep_last),
+        ("--keep-hourly", policy.keep_hourly),
+        ("--keep-daily", policy.keep_daily),
+        ("--keep-weekly", policy.keep_weekly),
+        ("--keep-monthly", policy.keep_monthly),
+        ("--keep-yearly", policy.keep_yearly),
+    ] {
+        if let Some(n) = value {
+            args.push(flag.to_string());
+            args.push(n.to_string());
+        }
+    }
+    if let Some(ref duration) = policy.keep_within {
+        let trimmed = duration.trim();
+        if !trimmed.is_empty() {
+            args.push("--keep-within".to_string());
+            args.push(trimmed.to_string());
+        }
+    }
+    for tags in &policy.keep_tags {
+        let trimmed = tags.trim();
+        if !trimmed.is_empty() {
+            args.push("--keep-tag".to_string());
+            args.push(trimmed.to_string());
         }
     }
+    args
+}
 
-    let command_preview = Some(
-        std::iter::once(state.rustic_bin.clone())
-            .chain(display_args.into_iter())
-            .collect::<Vec<_>>(),
-    );
-    let worker = worker_runtime_stats(&state);
-    let rustic = run_rustic_command(&state, args, env_vars, command_preview).await?;
+fn plan_snapshot_filter_args(state: &AppState, request: &RusticBackupRequest) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(host) = request
+        .host
+        .as_deref()
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+        .or(state.default_backup_host.as_deref())
+    {
+        args.push("--filter-host".to_string());
+        args.push(host.to_string());
+    }
+    if let Some(label) = request
+        .label
+        .as_deref()
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+    {
+        args.push("--filter-label".to_string());
+        args.push(label.to_string());
+    }
+    let tags = request
+        .tags
+        .iter()
+        .flatten()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>();
+    if !tags.is_empty() {
+        args.push("--filter-tags".to_string());
+        args.push(tags.join(","));
+    }
+    if request.source.is_none() && !request.paths.is_empty() {
+        args.push("--filter-paths".to_string());
+        args.push(request.paths.join(","));
+    }
+    args
+}
 
-    if !rustic.success {
-        let reason =
-            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| "rustic init failed".to_string());
-        let status = if is_already_initialized_message(&reason) {
-            StatusCode::CONFLICT
-        } else {
-            StatusCode::BAD_GATEWAY
-        };
-        return Err(api_error(status, reason));
+fn plan_prune_due(state: &AppState, plan_id: &str, interval_hours: u32) -> bool {
+    if interval_hours == 0 {
+        return false;
     }
+    let last_prune = state
+        .plan_last_prunes
+        .lock()
+        .map(|prunes| prunes.get(plan_id).copied())
+        .unwrap_or_default();
+    last_prune.is_none_or(|last_prune| {
+        Utc::now().timestamp() - last_prune >= i64::from(interval_hours) * 3600
+    })
+}
 
-    Ok(Json(RusticInitResponse { worker, rustic }))
+fn record_plan_prune(state: &AppState, plan_id: &str) {
+    if let Ok(mut prunes) = state.plan_last_prunes.lock() {
+        prunes.insert(plan_id.to_string(), Utc::now().timestamp());
+        save_plan_prunes(&state.state_dir, &prunes);
+    } else {
+        log_error("failed to lock plan prune times");
+    }
 }
 
-async fn bearer_auth(
-    State(state): State<AppState>,
-    headers: HeaderMap,
-    req: Request,
-    next: Next,
-) -> Result<Response, StatusCode> {
-    let auth_header = headers
-        .get(axum::http::header::AUTHORIZATION)
-        .and_then(|v| v.to_str().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+async fn run_plan_retention(
+    state: &AppState,
+    operation: &OperationContext,
+    plan: &SyncedBackupPlan,
+    retention: &PlanRetention,
+    report: &mut RetentionRunReport,
+) -> Result<(), (StatusCode, Json<ApiErrorResponse>)> {
+    let keep_args = retention_keep_args(&retention.keep);
+    if keep_args.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "retention policy has no keep rules; refusing to forget",
+        ));
+    }
+
+    let request = &plan.request;
+    let _slot = acquire_operation_slot(
+        state,
+        operation,
+        &request.repository,
+        RepositoryAccess::Exclusive,
+    )
+    .await?;
+    let repository = prepare_repository_for_rclone(
+        state,
+        operation,
+        request.repository.trim().to_string(),
+        request.backend.as_deref(),
+        &request.options.clone().unwrap_or_default(),
+        "retention",
+    )
+    .await?;
+    let mut env_vars = Vec::new();
+    if let Some(password) = request.password.as_ref()
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password.clone()));
+    }
+
+    let mut args = vec![
+        "--repository".to_string(),
+        repository.clone(),
+        "forget".to_string(),
+        "--json".to_string(),
+        "--no-progress".to_string(),
+    ];
+    let dry_run = request.dry_run.unwrap_or(false);
+    if dry_run {
+        args.push("--dry-run".to_string());
+    }
+    args.extend(plan_snapshot_filter_args(state, request));
+    args.extend(keep_args);
+    let forget = run_rustic_command(state, operation, args, env_vars.clone(), None).await?;
+    let forget_failed = (!forget.success).then(|| {
+        first_useful_error_line(&forget.stderr)
+            .unwrap_or_else(|| "forget command failed".to_string())
+    });
+    report.forget = Some(forget);
+    if let Some(reason) = forget_failed {
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    }
+
+    if dry_run || !plan_prune_due(state, &plan.id, retention.prune_interval_hours) {
+        return Ok(());
+    }
+    log_info(format!("pruning repository for plan_id={}", plan.id));
+    let prune = run_rustic_command(
+        state,
+        operation,
+        vec![
+            "--repository".to_string(),
+            repository,
+            "prune".to_string(),
+            "--no-progress".to_string(),
+        ],
+        env_vars,
+        None,
+    )
+    .await?;
+    let prune_failed = (!prune.success).then(|| {
+        first_useful_error_line(&prune.stderr).unwrap_or_else(|| "prune command failed".to_string())
+    });
+    report.prune = Some(prune);
+    if let Some(reason) = prune_failed {
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    }
+    record_plan_prune(state, &plan.id);
+    Ok(())
+}
+
+async fn apply_plan_retention(
+    state: &AppState,
+    operation: &OperationContext,
+    plan: &SyncedBackupPlan,
+) -> Option<RetentionRunReport> {
+    if plan.operation != PlanOperation::Backup {
+        return None;
+    }
+    let retention = plan.retention.as_ref()?;
+    let mut report = RetentionRunReport::default();
+    if let Err((_, error)) =
+        run_plan_retention(state, operation, plan, retention, &mut report).await
+    {
+        log_warn(format!(
+            "retention failed plan_id={}: {}",
+            plan.id, error.0.error
+        ));
+        report.error = Some(error.0.error);
+    }
+    Some(report)
+}
+
+fn validate_read_data_subset(subset: &str) -> Result<(), String> {
+    let valid = if let Some(percent) = subset.strip_suffix('%') {
+        percent
+            .parse::<f64>()
+            .is_ok_and(|percent| percent > 0.0 && percent <= 100.0)
+    } else if let Some((n, m)) = subset.split_once('/') {
+        matches!(
+            (n.parse::<u32>(), m.parse::<u32>()),
+            (Ok(n), Ok(m)) if n >= 1 && n <= m
+        )
+    } else {
+        parse_size_limit(subset).is_some_and(|bytes| bytes > 0)
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid readDataSubset '{subset}'; use n/m, a percentage or a size"
+        ))
+    }
+}
+
+fn maintenance_command_args(
+    state: &AppState,
+    plan: &SyncedBackupPlan,
+) -> Result<Vec<String>, String> {
+    let mut args = match plan.operation {
+        PlanOperation::Backup => return Err("backup is not a maintenance operation".to_string()),
+        PlanOperation::Check => vec!["check".to_string(), "--no-progress".to_string()],
+        PlanOperation::CheckReadDataSubset => {
+            let subset = plan
+                .read_data_subset
+                .as_deref()
+                .map(str::trim)
+                .filter(|subset| !subset.is_empty())
+                .unwrap_or(DEFAULT_READ_DATA_SUBSET);
+            validate_read_data_subset(subset)?;
+            vec![
+                "check".to_string(),
+                "--no-progress".to_string(),
+                "--read-data".to_string(),
+                "--read-data-subset".to_string(),
+                subset.to_string(),
+            ]
+        }
+        PlanOperation::Forget => {
+            let keep_args = plan
+                .retention
+                .as_ref()
+                .map(|retention| retention_keep_args(&retention.keep))
+                .unwrap_or_default();
+            if keep_args.is_empty() {
+                return Err(
+                    "forget plans require retention with at least one keep rule".to_string()
+                );
+            }
+            let mut args = vec![
+                "forget".to_string(),
+                "--json".to_string(),
+                "--no-progress".to_string(),
+            ];
+            args.extend(plan_snapshot_filter_args(state, &plan.request));
+            args.extend(keep_args);
+            args
+        }
+        PlanOperation::Prune => vec!["prune".to_string(), "--no-progress".to_string()],
+        PlanOperation::RepairIndex => vec![
+            "repair".to_string(),
+            "index".to_string(),
+            "--no-progress".to_string(),
+        ],
+    };
+    if plan.request.dry_run.unwrap_or(false)
+        && matches!(
+            plan.operation,
+            PlanOperation::Forget | PlanOperation::Prune | PlanOperation::RepairIndex
+        )
+    {
+        args.push("--dry-run".to_string());
+    }
+    Ok(args)
+}
+
+async fn run_maintenance_operation(
+    state: &AppState,
+    operation: &OperationContext,
+    plan: &SyncedBackupPlan,
+) -> Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)> {
+    let name = plan.operation.as_str();
+    let request = &plan.request;
+    let repository = request.repository.trim();
+    if repository.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            format!("repository is required for {name}"),
+        ));
+    }
+    let command_args = maintenance_command_args(state, plan)
+        .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+    let repository = prepare_repository_for_rclone(
+        state,
+        operation,
+        repository.to_string(),
+        request.backend.as_deref(),
+        &request.options.clone().unwrap_or_default(),
+        name,
+    )
+    .await?;
+
+    let mut env_vars = Vec::new();
+    if let Some(password) = request.password.as_ref()
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password.clone()));
+    }
+
+    let mut args = vec!["--repository".to_string(), repository];
+    args.extend(command_args);
+    let rustic = run_rustic_command(state, operation, args, env_vars, None).await?;
+    if !rustic.success {
+        let reason =
+            first_useful_error_line(&rustic.stderr).unwrap_or_else(|| format!("{name} failed"));
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    }
+    Ok(rustic)
+}
+
+async fn execute_plan_operation(
+    state: &AppState,
+    operation: &OperationContext,
+    plan: &SyncedBackupPlan,
+) -> (
+    Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)>,
+    Vec<BackupHookResult>,
+) {
+    match plan.operation {
+        PlanOperation::Backup => {
+            execute_backup_request(state, operation, plan.request.clone()).await
+        }
+        _ => (
+            run_maintenance_operation(state, operation, plan).await,
+            Vec::new(),
+        ),
+    }
+}
+
+fn attach_report_output(report: &mut BackupPlanReportRequest, key: &str, value: Value) {
+    if !report.output.is_object() {
+        report.output = Value::Object(serde_json::Map::new());
+    }
+    if let Value::Object(output) = &mut report.output {
+        output.insert(key.to_string(), value);
+    }
+}
+
+fn validate_forget_group_by(group_by: &str) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for criterion in group_by.split(',').map(str::trim) {
+        if !FORGET_GROUP_CRITERIA.contains(&criterion) {
+            return Err(format!(
+                "invalid groupBy '{group_by}'; use a comma-separated list of {}",
+                FORGET_GROUP_CRITERIA.join(", ")
+            ));
+        }
+        if seen.contains(&criterion) {
+            return Err(format!("groupBy lists '{criterion}' more than once"));
+        }
+        seen.push(criterion);
+    }
+    Ok(())
+}
+
+fn forget_selection_args(payload: &RusticForgetRequest) -> Result<Vec<String>, String> {
+    if payload
+        .retention
+        .keep_tags
+        .iter()
+        .any(|tags| tags.trim().is_empty())
+    {
+        return Err("keepTags entries must not be empty".to_string());
+    }
+    let mut args = retention_keep_args(&payload.retention);
+    if payload.keep_none {
+        if !args.is_empty() {
+            return Err("keepNone cannot be combined with other keep rules".to_string());
+        }
+        args.push("--keep-none".to_string());
+    } else if args.is_empty() {
+        return Err(
+            "forget requires at least one keep rule; set keepNone to remove every matching snapshot"
+                .to_string(),
+        );
+    }
+
+    if let Some(group_by) = payload.group_by.as_deref().map(str::trim) {
+        validate_forget_group_by(group_by)?;
+        args.push("--group-by".to_string());
+        args.push(group_by.to_string());
+    }
+    for (flag, name, values) in [
+        ("--filter-host", "filterHosts", &payload.filter_hosts),
+        ("--filter-tags", "filterTags", &payload.filter_tags),
+        ("--filter-paths", "filterPaths", &payload.filter_paths),
+    ] {
+        for value in values {
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                return Err(format!("{name} entries must not be empty"));
+            }
+            args.push(flag.to_string());
+            args.push(trimmed.to_string());
+        }
+    }
+    Ok(args)
+}
+
+fn parse_forget_groups(parsed_json: Option<&Value>) -> Vec<ForgetGroupSummary> {
+    let Some(groups) = parsed_json.and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    groups
+        .iter()
+        .map(|group| {
+            let mut summary = ForgetGroupSummary {
+                group: group.get("group").cloned().unwrap_or(Value::Null),
+                kept: Vec::new(),
+                removed: Vec::new(),
+            };
+            for entry in group
+                .get("snapshots")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let snapshot = entry.get("snapshot");
+                let Some(id) = snapshot
+                    .and_then(|snapshot| snapshot.get("id"))
+                    .and_then(Value::as_str)
+                else {
+                    continue;
+                };
+                let snapshot_summary = ForgetSnapshotSummary {
+                    id: id.to_string(),
+                    time: snapshot
+                        .and_then(|snapshot| snapshot.get("time"))
+                        .and_then(Value::as_str)
+                        .map(ToOwned::to_owned),
+                    reasons: entry
+                        .get("reasons")
+                        .and_then(Value::as_array)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Value::as_str)
+                        .map(ToOwned::to_owned)
+                        .collect(),
+                };
+                if entry.get("keep").and_then(Value::as_bool).unwrap_or(true) {
+                    summary.kept.push(snapshot_summary);
+                } else {
+                    summary.removed.push(snapshot_summary);
+                }
+            }
+            summary
+        })
+        .collect()
+}
+
+async fn rustic_forget(
+    State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
+    Json(payload): Json<RusticForgetRequest>,
+) -> Result<Json<RusticForgetResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let mut repository = payload.repository.trim().to_string();
+    if repository.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "repository is required for forget",
+        ));
+    }
+    let selection_args = forget_selection_args(&payload)
+        .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+    let _slot =
+        acquire_operation_slot(&state, &operation, &repository, RepositoryAccess::Exclusive)
+            .await?;
+
+    let options = payload.options.unwrap_or_default();
+    if payload.backend.as_deref() == Some("rclone") {
+        let remote_from_repository = repository
+            .strip_prefix("rclone:")
+            .and_then(|value| value.split_once(':'))
+            .map(|(remote, _)| remote.to_string());
+        let remote_name = options
+            .get("rclone.remote")
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .or(remote_from_repository)
+            .ok_or_else(|| {
+                api_error(
+                    StatusCode::BAD_REQUEST,
+                    "rclone backend requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
+                )
+            })?;
+        let rclone_type = options
+            .get("rclone.type")
+            .or_else(|| options.get("rclone.config.type"))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                api_error(
+                    StatusCode::BAD_REQUEST,
+                    "rclone backend requires option `rclone.type` (example: rclone.type=s3)",
+                )
+            })?;
+
+        let mut rclone_args = vec![
+            "config".to_string(),
+            "create".to_string(),
+            remote_name.clone(),
+            rclone_type,
+        ];
+        let mut rclone_preview = vec![
+            "rclone".to_string(),
+            "config".to_string(),
+            "create".to_string(),
+            remote_name.clone(),
+            options
+                .get("rclone.type")
+                .or_else(|| options.get("rclone.config.type"))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default(),
+        ];
+
+        let mut rclone_config_items = options
+            .iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix("rclone.config.")
+                    .filter(|trimmed| !trimmed.is_empty() && *trimmed != "type")
+                    .map(|trimmed| (trimmed.to_string(), value.to_string()))
+            })
+            .collect::<Vec<_>>();
+        rclone_config_items.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (key, value) in rclone_config_items {
+            rclone_args.push(key.clone());
+            rclone_args.push(value.clone());
+            rclone_preview.push(key.clone());
+            rclone_preview.push(if is_sensitive_key(&key) {
+                "***".to_string()
+            } else {
+                value
+            });
+        }
+        rclone_args.push("--non-interactive".to_string());
+        rclone_preview.push("--non-interactive".to_string());
+
+        let rclone_result =
+            run_rclone_command(&state, &operation, rclone_args, rclone_preview).await?;
+        if !rclone_result.success {
+            let reason = first_useful_error_line(&rclone_result.stderr)
+                .unwrap_or_else(|| "rclone config create failed".to_string());
+            return Err(api_error(
+                StatusCode::BAD_GATEWAY,
+                format!("failed to create rclone config: {reason}"),
+            ));
+        }
+
+        if !repository.starts_with("rclone:") {
+            let normalized_path = repository.trim_start_matches('/');
+            repository = format!("rclone:{remote_name}:{normalized_path}");
+            log_info(format!(
+                "normalized repository for rclone forget: {repository}"
+            ));
+        }
+    }
+
+    let mut args = vec![
+        "--repository".to_string(),
+        repository,
+        "forget".to_string(),
+        "--json".to_string(),
+        "--no-progress".to_string(),
+    ];
+
+    args.extend(selection_args);
+    if payload.prune == Some(true) {
+        args.push("--prune".to_string());
+    }
+    if payload.dry_run == Some(true) {
+        args.push("--dry-run".to_string());
+    }
+
+    let mut env_vars = Vec::new();
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    }
+
+    let worker = worker_runtime_stats(&state);
+    let rustic = run_rustic_command(&state, &operation, args, env_vars, None).await?;
+
+    if !rustic.success {
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "forget command failed".to_string());
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    }
+
+    let groups = parse_forget_groups(rustic.parsed_json.as_ref());
+    Ok(Json(RusticForgetResponse {
+        worker,
+        groups,
+        rustic,
+    }))
+}
+
+fn validate_prune_limit(name: &str, value: &str) -> Result<(), String> {
+    let valid = if value.eq_ignore_ascii_case("unlimited") {
+        true
+    } else if let Some(percent) = value.strip_suffix('%') {
+        percent
+            .trim()
+            .parse::<f64>()
+            .is_ok_and(|percent| (0.0..=100.0).contains(&percent))
+    } else {
+        parse_size_limit(value).is_some()
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid {name} '{value}'; use 'unlimited', a percentage or a size"
+        ))
+    }
+}
+
+fn validate_prune_duration(name: &str, value: &str) -> Result<(), String> {
+    const UNITS: [&str; 30] = [
+        "ns", "us", "ms", "s", "sec", "secs", "second", "seconds", "m", "min", "mins", "minute",
+        "minutes", "h", "hr", "hrs", "hour", "hours", "d", "day", "days", "w", "week", "weeks",
+        "M", "month", "months", "y", "year", "years",
+    ];
+    let mut rest = value.trim_start();
+    if rest.is_empty() {
+        return Err(format!("{name} must not be empty"));
+    }
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit_len = rest[digits..]
+            .find(|ch: char| !ch.is_ascii_alphabetic())
+            .unwrap_or(rest.len() - digits);
+        let unit = &rest[digits..digits + unit_len];
+        if digits == 0 || !UNITS.contains(&unit) {
+            return Err(format!(
+                "invalid {name} '{value}'; use a duration such as '0d', '23h' or '1d 12h'"
+            ));
+        }
+        rest = rest[digits + unit_len..].trim_start();
+    }
+    Ok(())
+}
+
+fn parse_prune_counts(text: &str) -> (Option<u64>, Option<u64>, Option<u64>) {
+    let (mut packs, mut blobs, mut bytes) = (None, None, None);
+    let text = text.split('(').next().unwrap_or_default();
+    for segment in text.split(',').map(str::trim) {
+        if let Some(count) = segment.strip_suffix("packs") {
+            packs = count.trim().parse::<u64>().ok();
+        } else if let Some(count) = segment.strip_suffix("blobs") {
+            blobs = count.trim().parse::<u64>().ok();
+        } else if let Some((number, unit)) = segment.split_once(char::is_whitespace) {
+            bytes = number
+                .parse::<f64>()
+                .ok()
+                .zip(parse_byte_unit(unit.trim()))
+                .map(|(number, multiplier)| (number * multiplier).round() as u64);
+        }
+    }
+    (packs, blobs, bytes)
+}
+
+fn parse_prune_stats(output: &str) -> PruneStats {
+    let mut stats = PruneStats::default();
+    for line in strip_ansi_codes(output).lines() {
+        let line = line.trim();
+        let line = match line.strip_prefix('[') {
+            Some(rest) => rest.split_once(']').map_or(line, |(_, rest)| rest.trim()),
+            None => line,
+        };
+        let Some((label, counts)) = line.split_once(':') else {
+            continue;
+        };
+        let (packs, blobs, bytes) = parse_prune_counts(counts);
+        match label.trim() {
+            "to repack" => {
+                stats.packs_to_repack = packs;
+                stats.blobs_to_repack = blobs;
+                stats.bytes_to_repack = bytes;
+            }
+            "this removes" => {
+                stats.blobs_removed_by_repack = blobs;
+                stats.bytes_removed_by_repack = bytes;
+            }
+            "to delete" => {
+                stats.packs_to_delete = packs;
+                stats.blobs_to_delete = blobs;
+                stats.bytes_to_delete = bytes;
+            }
+            "total prune" => {
+                stats.blobs_pruned = blobs;
+                stats.bytes_freed = bytes;
+            }
+            "remaining" => {
+                stats.blobs_remaining = blobs;
+                stats.bytes_remaining = bytes;
+            }
+            "unused size after prune" => stats.unused_bytes_after_prune = bytes,
+            _ => {}
+        }
+    }
+    if stats.bytes_freed.is_none()
+        && (stats.bytes_to_delete.is_some() || stats.bytes_removed_by_repack.is_some())
+    {
+        stats.bytes_freed = Some(
+            stats.bytes_to_delete.unwrap_or_default()
+                + stats.bytes_removed_by_repack.unwrap_or_default(),
+        );
+    }
+    stats
+}
+
+async fn rustic_prune(
+    State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
+    Json(payload): Json<RusticPruneRequest>,
+) -> Result<Json<RusticPruneResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let repository = payload.repository.trim();
+    if repository.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "repository is required for prune",
+        ));
+    }
+
+    let mut prune_args = Vec::new();
+    for (flag, name, value) in [
+        ("--max-unused", "maxUnused", &payload.max_unused),
+        ("--max-repack", "maxRepack", &payload.max_repack),
+    ] {
+        if let Some(value) = value.as_deref().map(str::trim) {
+            validate_prune_limit(name, value)
+                .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+            prune_args.push(flag.to_string());
+            prune_args.push(value.to_string());
+        }
+    }
+    for (flag, name, value) in [
+        ("--keep-pack", "keepPack", &payload.keep_pack),
+        ("--keep-delete", "keepDelete", &payload.keep_delete),
+    ] {
+        if let Some(value) = value.as_deref().map(str::trim) {
+            validate_prune_duration(name, value)
+                .map_err(|reason| api_error(StatusCode::BAD_REQUEST, reason))?;
+            prune_args.push(flag.to_string());
+            prune_args.push(value.to_string());
+        }
+    }
+    if let Some(cacheable_only) = payload.repack_cacheable_only {
+        prune_args.push("--repack-cacheable-only".to_string());
+        prune_args.push(cacheable_only.to_string());
+    }
+    let dry_run = payload.dry_run == Some(true);
+    if dry_run {
+        prune_args.push("--dry-run".to_string());
+    }
+
+    let _slot =
+        acquire_operation_slot(&state, &operation, repository, RepositoryAccess::Exclusive).await?;
+    let repository = prepare_repository_for_rclone(
+        &state,
+        &operation,
+        repository.to_string(),
+        payload.backend.as_deref(),
+        &payload.options.unwrap_or_default(),
+        "prune",
+    )
+    .await?;
+
+    let mut env_vars = Vec::new();
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    }
+
+    let mut args = vec![
+        "--repository".to_string(),
+        repository,
+        "prune".to_string(),
+        "--no-progress".to_string(),
+    ];
+    args.extend(prune_args);
+
+    let worker = worker_runtime_stats(&state);
+    let rustic = run_rustic_command(&state, &operation, args, env_vars, None).await?;
+
+    if !rustic.success {
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "prune command failed".to_string());
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    }
+
+    let summary = strip_ansi_codes(&format!("{}\n{}", rustic.stdout, rustic.stderr))
+        .trim()
+        .to_string();
+    let stats = parse_prune_stats(&summary);
+    Ok(Json(RusticPruneResponse {
+        worker,
+        dry_run,
+        stats,
+        stats_best_effort: true,
+        summary,
+        rustic,
+    }))
+}
+
+async fn rustic_restore(
+    State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
+    Json(payload): Json<RusticRestoreRequest>,
+) -> Result<Json<RusticRepositoryCommandResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let repository = payload.repository.trim();
+    let snapshot = payload.snapshot.trim();
+    let target = payload.target.trim();
+    if repository.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "repository is required for restore",
+        ));
+    }
+    if snapshot.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "snapshot is required for restore",
+        ));
+    }
+    if target.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "target is required for restore",
+        ));
+    }
+
+    let _slot =
+        acquire_operation_slot(&state, &operation, repository, RepositoryAccess::Shared).await?;
+    let repository = prepare_repository_for_rclone(
+        &state,
+        &operation,
+        repository.to_string(),
+        payload.backend.as_deref(),
+        &payload.options.unwrap_or_default(),
+        "restore",
+    )
+    .await?;
+
+    let mut env_vars = Vec::new();
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    }
+
+    let mut args = vec![
+        "--repository".to_string(),
+        repository,
+        "restore".to_string(),
+        "--progress-interval".to_string(),
+        PROGRESS_INTERVAL.to_string(),
+        "--target".to_string(),
+        target.to_string(),
+        snapshot.to_string(),
+    ];
+    if let Some(path) = payload.path {
+        let trimmed = path.trim();
+        if !trimmed.is_empty() {
+            args.push(trimmed.to_string());
+        }
+    }
+    if payload.dry_run == Some(true) {
+        args.push("--dry-run".to_string());
+    }
+
+    let worker = worker_runtime_stats(&state);
+    let rustic = run_rustic_command(&state, &operation, args, env_vars, None).await?;
+    if !rustic.success {
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "restore command failed".to_string());
+        return Err(api_error(StatusCode::BAD_GATEWAY, reason));
+    }
+
+    Ok(Json(RusticRepositoryCommandResponse { worker, rustic }))
+}
+
+async fn ls_dirs(
+    Json(payload): Json<LsDirsRequest>,
+) -> Result<Json<LsDirsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let path = payload
+        .path
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or("/")
+        .to_string();
+
+    let mut dirs = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(&path) {
+        for entry in entries.flatten() {
+            if let Ok(file_type) = entry.file_type()
+                && file_type.is_dir()
+                && let Some(name) = entry.file_name().to_str()
+                && !name.starts_with('.')
+            {
+                dirs.push(format!("{}/{}", path.trim_end_matches('/'), name));
+            }
+        }
+    }
+    dirs.sort();
+    Ok(Json(LsDirsResponse { dirs }))
+}
+
+async fn rustic_init(
+    State(state): State<AppState>,
+    Extension(operation): Extension<OperationContext>,
+    Json(payload): Json<RusticInitRequest>,
+) -> Result<Json<RusticInitResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let mut repository = payload.repository.trim().to_string();
+    log_info(format!("received init request for repository={repository}"));
+    if repository.is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "repository is required for init",
+        ));
+    }
+    let _slot =
+        acquire_operation_slot(&state, &operation, &repository, RepositoryAccess::Exclusive)
+            .await?;
+
+    let options = payload.options.unwrap_or_default();
+    if payload.backend.as_deref() == Some("rclone") {
+        let remote_from_repository = repository
+            .strip_prefix("rclone:")
+            .and_then(|value| value.split_once(':'))
+            .map(|(remote, _)| remote.to_string());
+        let remote_name = options
+            .get("rclone.remote")
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .or(remote_from_repository)
+            .ok_or_else(|| {
+                api_error(
+                    StatusCode::BAD_REQUEST,
+                    "rclone backend requires `rclone.remote` option or repository path in format rclone:<remote>:<path>",
+                )
+            })?;
+        let rclone_type = options
+            .get("rclone.type")
+            .or_else(|| options.get("rclone.config.type"))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                api_error(
+                    StatusCode::BAD_REQUEST,
+                    "rclone backend requires option `rclone.type` (example: rclone.type=s3)",
+                )
+            })?;
+
+        let mut rclone_args = vec![
+            "config".to_string(),
+            "create".to_string(),
+            remote_name.clone(),
+            rclone_type,
+        ];
+        let mut rclone_preview = vec![
+            "rclone".to_string(),
+            "config".to_string(),
+            "create".to_string(),
+            remote_name.clone(),
+            options
+                .get("rclone.type")
+                .or_else(|| options.get("rclone.config.type"))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default(),
+        ];
+
+        let mut rclone_config_items = options
+            .iter()
+            .filter_map(|(key, value)| {
+                key.strip_prefix("rclone.config.")
+                    .filter(|trimmed| !trimmed.is_empty() && *trimmed != "type")
+                    .map(|trimmed| (trimmed.to_string(), value.to_string()))
+            })
+            .collect::<Vec<_>>();
+        rclone_config_items.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (key, value) in rclone_config_items {
+            rclone_args.push(key.clone());
+            rclone_args.push(value.clone());
+            rclone_preview.push(key.clone());
+            rclone_preview.push(if is_sensitive_key(&key) {
+                "***".to_string()
+            } else {
+                value
+            });
+        }
+        rclone_args.push("--non-interactive".to_string());
+        rclone_preview.push("--non-interactive".to_string());
+
+        let rclone_result =
+            run_rclone_command(&state, &operation, rclone_args, rclone_preview).await?;
+        if !rclone_result.success {
+            let reason = first_useful_error_line(&rclone_result.stderr)
+                .unwrap_or_else(|| "rclone config create failed".to_string());
+            return Err(api_error(
+                StatusCode::BAD_GATEWAY,
+                format!("failed to create rclone config: {reason}"),
+            ));
+        }
+
+        if !repository.starts_with("rclone:") {
+            let normalized_path = repository.trim_start_matches('/');
+            repository = format!("rclone:{remote_name}:{normalized_path}");
+            log_info(format!(
+                "normalized repository for rclone backend: {repository}"
+            ));
+        }
+    } else if !options.is_empty() {
+        log_warn(
+            "init received repository options, but this rustic build does not support --option; ignoring options",
+        );
+    }
+
+    let mut args = vec!["--repository".to_string(), repository];
+    let mut display_args = args.clone();
+    args.push("init".to_string());
+    args.push("--no-progress".to_string());
+    display_args.push("init".to_string());
+    display_args.push("--no-progress".to_string());
+
+    let mut env_vars = Vec::new();
+    if let Some(password) = payload.password
+        && !password.trim().is_empty()
+    {
+        env_vars.push(("RUSTIC_PASSWORD".to_string(), password));
+    }
+
+    let command_preview = Some(
+        std::iter::once(state.rustic_bin.clone())
+            .chain(display_args)
+            .collect::<Vec<_>>(),
+    );
+    let worker = worker_runtime_stats(&state);
+    let rustic = run_rustic_command(&state, &operation, args, env_vars, command_preview).await?;
+
+    if !rustic.success {
+        let reason = first_useful_error_line(&rustic.stderr)
+            .unwrap_or_else(|| "rustic init failed".to_string());
+        let status = if is_already_initialized_message(&reason) {
+            StatusCode::CONFLICT
+        } else {
+            StatusCode::BAD_GATEWAY
+        };
+        return Err(api_error(status, reason));
+    }
+
+    Ok(Json(RusticInitResponse { worker, rustic }))
+}
+
+async fn bearer_auth(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let auth_header = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let provided = auth_header
         .strip_prefix("Bearer ")
         .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    if provided != state.bearer_token {
-        return Err(StatusCode::UNAUTHORIZED);
+    if provided != state.bearer_token {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(next.run(req).await)
+}
+
+async fn request_logger(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let start = Instant::now();
+
+    let response = next.run(req).await;
+    let status = response.status().as_u16();
+    let elapsed = start.elapsed().as_millis();
+
+    state.requests_total.fetch_add(1, Ordering::Relaxed);
+    if status >= 500 {
+        state.error_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    if status >= 500 {
+        log_error(format!("{method} {path} -> {status} ({elapsed}ms)"));
+    } else if status >= 400 {
+        log_warn(format!("{method} {path} -> {status} ({elapsed}ms)"));
+    } else {
+        log_info(format!("{method} {path} -> {status} ({elapsed}ms)"));
+    }
+    response
+}
+
+async fn sync_stats_loop(state: AppState) {
+    let mut interval = time::interval(Duration::from_secs(15));
+    let sync_url = format!(
+        "{}/api/workers/sync",
+        state.master_api_endpoint.trim_end_matches('/')
+    );
+
+    loop {
+        interval.tick().await;
+
+        let payload = WorkerSyncPayload {
+            status: "online",
+            endpoint: state.local_api_endpoint.clone(),
+            uptime_ms: state.started_at.elapsed().as_millis() as u64,
+            requests_total: state.requests_total.load(Ordering::Relaxed),
+            error_total: state.error_total.load(Ordering::Relaxed),
+            jobs: collect_jobs(&state),
+        };
+
+        let response = state
+            .client
+            .post(&sync_url)
+            .header("Authorization", format!("Bearer {}", state.bearer_token))
+            .json(&payload)
+            .send()
+            .await;
+
+        match response {
+            Ok(response) if response.status().is_success() => {
+                log_info(format!(
+                    "worker sync success endpoint={} status={} req={} err={}",
+                    payload.endpoint, payload.status, payload.requests_total, payload.error_total
+                ));
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "unable to read response body".to_string());
+                log_error(format!("worker stats sync failed: {status} - {body}"));
+            }
+            Err(error) => {
+                log_error(format!("worker stats sync failed: {error}"));
+            }
+        }
+    }
+}
+
+fn extract_snapshot_ref(parsed_json: Option<&Value>) -> (Option<String>, Option<String>) {
+    let Some(value) = parsed_json else {
+        return (None, None);
+    };
+
+    let candidate = if let Some(array) = value.as_array() {
+        array.first()
+    } else {
+        Some(value)
+    };
+
+    let Some(object) = candidate.and_then(|entry| entry.as_object()) else {
+        return (None, None);
+    };
+
+    let snapshot_id = object
+        .get("snapshotId")
+        .and_then(Value::as_str)
+        .or_else(|| object.get("id").and_then(Value::as_str))
+        .map(ToOwned::to_owned);
+    let snapshot_time = object
+        .get("snapshotTime")
+        .and_then(Value::as_str)
+        .or_else(|| object.get("time").and_then(Value::as_str))
+        .map(ToOwned::to_owned);
+
+    (snapshot_id, snapshot_time)
+}
+
+#[derive(Clone, Copy)]
+enum CronField {
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+impl CronField {
+    fn label(self) -> &'static str {
+        match self {
+            CronField::Minute => "minute",
+            CronField::Hour => "hour",
+            CronField::DayOfMonth => "day-of-month",
+            CronField::Month => "month",
+            CronField::DayOfWeek => "day-of-week",
+        }
     }
 
-    Ok(next.run(req).await)
+    fn bounds(self) -> (u32, u32) {
+        match self {
+            CronField::Minute => (0, 59),
+            CronField::Hour => (0, 23),
+            CronField::DayOfMonth => (1, 31),
+            CronField::Month => (1, 12),
+            CronField::DayOfWeek => (0, 7),
+        }
+    }
+
+    fn names(self) -> &'static [&'static str] {
+        match self {
+            CronField::Month => &[
+                "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+            ],
+            CronField::DayOfWeek => &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+            _ => &[],
+        }
+    }
+
+    fn first_named_value(self) -> u32 {
+        match self {
+            CronField::Month => 1,
+            _ => 0,
+        }
+    }
 }
 
-async fn request_logger(State(state): State<AppState>, req: Request, next: Next) -> Response {
-    let method = req.method().clone();
-    let path = req.uri().path().to_string();
-    let start = Instant::now();
+#[derive(Clone, Copy)]
+enum DayOfMonthRule {
+    LastDay { offset: u32 },
+    LastWeekday,
+    NearestWeekday(u32),
+}
 
-    let response = next.run(req).await;
-    let status = response.status().as_u16();
-    let elapsed = start.elapsed().as_millis();
+#[derive(Clone, Copy)]
+enum DayOfWeekRule {
+    LastInMonth(u32),
+    NthInMonth { weekday: u32, nth: u32 },
+}
+
+fn parse_cron_value(raw: &str, field: CronField) -> Result<u32, String> {
+    if let Ok(value) = raw.parse::<u32>() {
+        return Ok(value);
+    }
+    let upper = raw.to_ascii_uppercase();
+    field
+        .names()
+        .iter()
+        .position(|name| *name == upper)
+        .map(|idx| idx as u32 + field.first_named_value())
+        .ok_or_else(|| format!("unknown value '{raw}'"))
+}
+
+fn parse_cron_chunk(
+    chunk: &str,
+    field: CronField,
+    values: &mut BTreeSet<u32>,
+) -> Result<(), String> {
+    let (min, max) = field.bounds();
+    let (base_raw, step_raw) = chunk
+        .split_once('/')
+        .map(|(base, step)| (base, Some(step)))
+        .unwrap_or((chunk, None));
+    let step = match step_raw {
+        Some(step_str) => step_str
+            .parse::<u32>()
+            .map_err(|_| format!("invalid step '{step_str}'"))?,
+        None => 1,
+    };
+    if step == 0 {
+        return Err("step must be greater than 0".to_string());
+    }
+
+    let (range_min, range_max) = if base_raw == "*" || base_raw == "?" {
+        let max = if matches!(field, CronField::DayOfWeek) {
+            6
+        } else {
+            max
+        };
+        (min, max)
+    } else if let Some((start_raw, end_raw)) = base_raw.split_once('-') {
+        let start = parse_cron_value(start_raw, field)?;
+        let mut end = parse_cron_value(end_raw, field)?;
+        if matches!(field, CronField::DayOfWeek) && end == 0 && start > 0 {
+            end = 7;
+        }
+        (start, end)
+    } else {
+        let single = parse_cron_value(base_raw, field)?;
+        if step_raw.is_some() {
+            (single, max)
+        } else {
+            (single, single)
+        }
+    };
+
+    if range_min < min || range_max > max {
+        return Err(format!("value out of range {min}-{max}"));
+    }
+    if range_min > range_max {
+        return Err(format!(
+            "range start {range_min} is after range end {range_max}"
+        ));
+    }
+
+    let mut value = range_min;
+    while value <= range_max {
+        if matches!(field, CronField::DayOfWeek) && value == 7 {
+            values.insert(0);
+        } else {
+            values.insert(value);
+        }
+        value = match value.checked_add(step) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+
+    Ok(())
+}
+
+fn parse_day_of_month_rule(chunk: &str) -> Result<Option<DayOfMonthRule>, String> {
+    let upper = chunk.to_ascii_uppercase();
+    if upper == "L" {
+        return Ok(Some(DayOfMonthRule::LastDay { offset: 0 }));
+    }
+    if upper == "LW" {
+        return Ok(Some(DayOfMonthRule::LastWeekday));
+    }
+    if upper.starts_with("L-") {
+        let offset_raw = &chunk[2..];
+        let offset = offset_raw
+            .parse::<u32>()
+            .map_err(|_| format!("invalid last-day offset '{offset_raw}'"))?;
+        if offset > 30 {
+            return Err("last-day offset must be between 0 and 30".to_string());
+        }
+        return Ok(Some(DayOfMonthRule::LastDay { offset }));
+    }
+    if upper.ends_with('W') {
+        let day_raw = &chunk[..chunk.len() - 1];
+        let day = day_raw
+            .parse::<u32>()
+            .map_err(|_| format!("invalid nearest-weekday day '{day_raw}'"))?;
+        if !(1..=31).contains(&day) {
+            return Err("nearest-weekday day must be between 1 and 31".to_string());
+        }
+        return Ok(Some(DayOfMonthRule::NearestWeekday(day)));
+    }
+    Ok(None)
+}
+
+fn parse_day_of_week_rule(chunk: &str) -> Result<Option<DayOfWeekRule>, String> {
+    let weekday = |raw: &str| -> Result<u32, String> {
+        let value = parse_cron_value(raw, CronField::DayOfWeek)?;
+        if value > 7 {
+            return Err("value out of range 0-7".to_string());
+        }
+        Ok(value % 7)
+    };
+
+    if let Some((weekday_raw, nth_raw)) = chunk.split_once('#') {
+        let weekday = weekday(weekday_raw)?;
+        let nth = nth_raw
+            .parse::<u32>()
+            .map_err(|_| format!("invalid occurrence '{nth_raw}'"))?;
+        if !(1..=5).contains(&nth) {
+            return Err("occurrence after '#' must be between 1 and 5".to_string());
+        }
+        return Ok(Some(DayOfWeekRule::NthInMonth { weekday, nth }));
+    }
+    if chunk.len() > 1
+        && let Some(weekday_raw) = chunk.strip_suffix('L').or_else(|| chunk.strip_suffix('l'))
+    {
+        return Ok(Some(DayOfWeekRule::LastInMonth(weekday(weekday_raw)?)));
+    }
+    Ok(None)
+}
+
+fn parse_cron_field(raw: &str, field: CronField) -> Result<BTreeSet<u32>, String> {
+    let mut values = BTreeSet::new();
+    for chunk_raw in raw.split(',') {
+        let chunk = chunk_raw.trim();
+        if chunk.is_empty() {
+            return Err(format!("{} field '{raw}': empty list entry", field.label()));
+        }
+        parse_cron_chunk(chunk, field, &mut values)
+            .map_err(|reason| format!("{} field '{raw}': {reason}", field.label()))?;
+    }
+    Ok(values)
+}
+
+fn parse_cron_day_field<R>(
+    raw: &str,
+    field: CronField,
+    parse_rule: fn(&str) -> Result<Option<R>, String>,
+) -> Result<(BTreeSet<u32>, Vec<R>), String> {
+    let mut values = BTreeSet::new();
+    let mut rules = Vec::new();
+    for chunk_raw in raw.split(',') {
+        let chunk = chunk_raw.trim();
+        if chunk.is_empty() {
+            return Err(format!("{} field '{raw}': empty list entry", field.label()));
+        }
+        let parsed = match parse_rule(chunk) {
+            Ok(Some(rule)) => {
+                rules.push(rule);
+                Ok(())
+            }
+            Ok(None) => parse_cron_chunk(chunk, field, &mut values),
+            Err(reason) => Err(reason),
+        };
+        parsed.map_err(|reason| format!("{} field '{raw}': {reason}", field.label()))?;
+    }
+    Ok((values, rules))
+}
+
+struct ParsedCron {
+    minute: BTreeSet<u32>,
+    hour: BTreeSet<u32>,
+    day_of_month: BTreeSet<u32>,
+    day_of_month_rules: Vec<DayOfMonthRule>,
+    month: BTreeSet<u32>,
+    day_of_week: BTreeSet<u32>,
+    day_of_week_rules: Vec<DayOfWeekRule>,
+    is_day_of_month_wildcard: bool,
+    is_day_of_week_wildcard: bool,
+}
+
+fn expand_cron_macro(cron: &str) -> Result<&str, String> {
+    match cron.to_ascii_lowercase().as_str() {
+        "@yearly" | "@annually" => Ok("0 0 1 1 *"),
+        "@monthly" => Ok("0 0 1 * *"),
+        "@weekly" => Ok("0 0 * * 0"),
+        "@daily" | "@midnight" => Ok("0 0 * * *"),
+        "@hourly" => Ok("0 * * * *"),
+        "@reboot" => Err("@reboot is not supported for backup plans".to_string()),
+        _ if cron.starts_with('@') => Err(format!(
+            "unknown macro '{cron}' (supported: @yearly, @annually, @monthly, @weekly, @daily, @midnight, @hourly)"
+        )),
+        _ => Ok(cron),
+    }
+}
+
+fn parse_cron_expression(cron: &str) -> Result<ParsedCron, String> {
+    let expanded = expand_cron_macro(cron.trim())?;
+    let parts = expanded.split_whitespace().collect::<Vec<_>>();
+    if parts.len() != 5 {
+        return Err(format!(
+            "expected 5 fields (minute hour day-of-month month day-of-week) or a macro such as @daily, got {}",
+            parts.len()
+        ));
+    }
+
+    let minute_field = parts[0];
+    let hour_field = parts[1];
+    let day_of_month_field = parts[2];
+    let month_field = parts[3];
+    let day_of_week_field = parts[4];
+
+    let minute = parse_cron_field(minute_field, CronField::Minute)?;
+    let hour = parse_cron_field(hour_field, CronField::Hour)?;
+    let month = parse_cron_field(month_field, CronField::Month)?;
+
+    let (day_of_month, day_of_month_rules) = parse_cron_day_field(
+        day_of_month_field,
+        CronField::DayOfMonth,
+        parse_day_of_month_rule,
+    )?;
+    let (day_of_week, day_of_week_rules) = parse_cron_day_field(
+        day_of_week_field,
+        CronField::DayOfWeek,
+        parse_day_of_week_rule,
+    )?;
+
+    Ok(ParsedCron {
+        minute,
+        hour,
+        day_of_month,
+        day_of_month_rules,
+        month,
+        day_of_week,
+        day_of_week_rules,
+        is_day_of_month_wildcard: matches!(day_of_month_field, "*" | "?"),
+        is_day_of_week_wildcard: matches!(day_of_week_field, "*" | "?"),
+    })
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|first| first.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(31)
+}
+
+fn nearest_weekday(date: NaiveDate, day: u32) -> Option<u32> {
+    let last_day = days_in_month(date.year(), date.month());
+    if day > last_day {
+        return None;
+    }
+    let target = date.with_day(day)?;
+    Some(match target.weekday() {
+        Weekday::Sat if day == 1 => 3,
+        Weekday::Sat => day - 1,
+        Weekday::Sun if day == last_day => day - 2,
+        Weekday::Sun => day + 1,
+        _ => day,
+    })
+}
+
+fn day_of_month_matches(parsed: &ParsedCron, date: NaiveDate) -> bool {
+    if parsed.day_of_month.contains(&date.day()) {
+        return true;
+    }
+    let last_day = days_in_month(date.year(), date.month());
+    parsed.day_of_month_rules.iter().any(|rule| match *rule {
+        DayOfMonthRule::LastDay { offset } => last_day.checked_sub(offset) == Some(date.day()),
+        DayOfMonthRule::LastWeekday => nearest_weekday(date, last_day) == Some(date.day()),
+        DayOfMonthRule::NearestWeekday(day) => nearest_weekday(date, day) == Some(date.day()),
+    })
+}
+
+fn day_of_week_matches(parsed: &ParsedCron, date: NaiveDate) -> bool {
+    let weekday = date.weekday().num_days_from_sunday();
+    if parsed.day_of_week.contains(&weekday) {
+        return true;
+    }
+    let last_day = days_in_month(date.year(), date.month());
+    parsed.day_of_week_rules.iter().any(|rule| match *rule {
+        DayOfWeekRule::LastInMonth(target) => target == weekday && date.day() + 7 > last_day,
+        DayOfWeekRule::NthInMonth {
+            weekday: target,
+            nth,
+        } => target == weekday && (date.day() - 1) / 7 + 1 == nth,
+    })
+}
+
+fn cron_day_matches(parsed: &ParsedCron, date: NaiveDate) -> bool {
+    if !parsed.month.contains(&date.month()) {
+        return false;
+    }
+
+    let dom_match = day_of_month_matches(parsed, date);
+    let dow_match = day_of_week_matches(parsed, date);
+    if parsed.is_day_of_month_wildcard && parsed.is_day_of_week_wildcard {
+        return true;
+    }
+    if parsed.is_day_of_month_wildcard {
+        return dow_match;
+    }
+    if parsed.is_day_of_week_wildcard {
+        return dom_match;
+    }
+
+    dom_match || dow_match
+}
+
+fn next_cron_match(parsed: &ParsedCron, from: NaiveDateTime) -> Option<NaiveDateTime> {
+    let limit_year = from.year() + CRON_SEARCH_YEARS;
+    let mut date = from.date();
+    let mut hour = from.hour();
+    let mut minute = from.minute();
+
+    loop {
+        if date.year() > limit_year {
+            return None;
+        }
+
+        if !parsed.month.contains(&date.month()) {
+            let (year, month) = match parsed.month.range(date.month() + 1..).next() {
+                Some(&month) => (date.year(), month),
+                None => (date.year() + 1, *parsed.month.first()?),
+            };
+            date = NaiveDate::from_ymd_opt(year, month, 1)?;
+            hour = 0;
+            minute = 0;
+            continue;
+        }
+
+        if !cron_day_matches(parsed, date) {
+            date = date.succ_opt()?;
+            hour = 0;
+            minute = 0;
+            continue;
+        }
+
+        let Some(&next_hour) = parsed.hour.range(hour..).next() else {
+            date = date.succ_opt()?;
+            hour = 0;
+            minute = 0;
+            continue;
+        };
+        if next_hour != hour {
+            hour = next_hour;
+            minute = 0;
+        }
+
+        let Some(&next_minute) = parsed.minute.range(minute..).next() else {
+            hour += 1;
+            minute = 0;
+            continue;
+        };
+
+        return date.and_hms_opt(hour, next_minute, 0);
+    }
+}
+
+fn next_cron_occurrences(
+    parsed: &ParsedCron,
+    time_zone: &TimeZone,
+    after: i64,
+    count: usize,
+) -> Vec<i64> {
+    let mut found = BTreeSet::new();
+    let Some(mut cursor) = local_minute_at(time_zone, after) else {
+        return Vec::new();
+    };
+    let mut horizon = None;
+
+    while let Some(next) = next_cron_match(parsed, cursor + ChronoDuration::minutes(1)) {
+        if horizon.is_some_and(|horizon| next > horizon) {
+            break;
+        }
+        if let Some(timestamp) = timestamp_for_local_minute(time_zone, next)
+            && timestamp > after
+        {
+            found.insert(timestamp);
+            if found.len() >= count && horizon.is_none() {
+                horizon = Some(next + ChronoDuration::minutes(DST_SCAN_SLACK_MINUTES));
+            }
+        }
+        cursor = next;
+    }
+
+    found.into_iter().take(count).collect()
+}
+
+fn resolve_plan_time_zone(name: Option<&str>) -> Result<TimeZone, String> {
+    match name.map(str::trim).filter(|value| !value.is_empty()) {
+        None => Ok(TimeZone::system()),
+        Some(name) => TimeZone::get(name).map_err(|_| {
+            format!("unknown time zone '{name}' (expected an IANA name such as Europe/Berlin)")
+        }),
+    }
+}
+
+fn parse_blackout_window(spec: &str) -> Result<BlackoutWindow, String> {
+    let mut tokens: Vec<&str> = spec.split_whitespace().collect();
+    let stop_running = tokens.last() == Some(&"stop");
+    if stop_running {
+        tokens.pop();
+    }
+
+    let period = match tokens.as_slice() {
+        [range] if range.contains('/') => {
+            let (from, until) = range.split_once('/').unwrap_or_default();
+            let parse_instant = |raw: &str| {
+                DateTime::parse_from_rfc3339(raw)
+                    .map(|value| value.timestamp())
+                    .map_err(|_| format!("invalid time '{raw}' (expected RFC 3339)"))
+            };
+            let starts_at = parse_instant(from)?;
+            let ends_at = parse_instant(until)?;
+            if ends_at <= starts_at {
+                return Err("end must be after start".to_string());
+            }
+            BlackoutPeriod::Once { starts_at, ends_at }
+        }
+        [days, times] => {
+            let days = parse_blackout_days(days)?;
+            let (start, end) = times
+                .split_once('-')
+                .ok_or_else(|| format!("invalid time range '{times}' (expected HH:MM-HH:MM)"))?;
+            let start_minute = parse_time_of_day(start)?;
+            let end_minute = parse_time_of_day(end)?;
+            if start_minute == end_minute {
+                return Err("start and end time must differ".to_string());
+            }
+            BlackoutPeriod::Weekly {
+                days,
+                start_minute,
+                end_minute,
+            }
+        }
+        _ => {
+            return Err(
+                "expected '<days> <HH:MM>-<HH:MM>' or '<start>/<end>' in RFC 3339, optionally followed by 'stop'"
+                    .to_string(),
+            );
+        }
+    };
+
+    Ok(BlackoutWindow {
+        spec: spec.trim().to_string(),
+        period,
+        stop_running,
+    })
+}
+
+fn parse_blackout_windows(specs: &[String]) -> Result<Vec<BlackoutWindow>, String> {
+    specs
+        .iter()
+        .map(|spec| {
+            parse_blackout_window(spec)
+                .map_err(|reason| format!("invalid blackout window '{spec}': {reason}"))
+        })
+        .collect()
+}
+
+fn parse_blackout_days(raw: &str) -> Result<[bool; 7], String> {
+    if raw == "*" {
+        return Ok([true; 7]);
+    }
+
+    let weekday_index = |name: &str| {
+        WEEKDAY_NAMES
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("unknown day '{name}' (expected sun..sat)"))
+    };
+    let mut days = [false; 7];
+    for part in raw.split(',') {
+        let (first, last) = match part.split_once('-') {
+            Some((first, last)) => (weekday_index(first)?, weekday_index(last)?),
+            None => {
+                let day = weekday_index(part)?;
+                (day, day)
+            }
+        };
+        let mut day = first;
+        loop {
+            days[day] = true;
+            if day == last {
+                break;
+            }
+            day = (day + 1) % 7;
+        }
+    }
+    Ok(days)
+}
+
+fn parse_time_of_day(raw: &str) -> Result<u32, String> {
+    let invalid = || format!("invalid time '{raw}' (expected HH:MM)");
+    let (hour, minute) = raw.split_once(':').ok_or_else(invalid)?;
+    let hour = hour.parse::<u32>().map_err(|_| invalid())?;
+    let minute = minute.parse::<u32>().map_err(|_| invalid())?;
+    if minute > 59 || hour > 24 || (hour == 24 && minute != 0) {
+        return Err(invalid());
+    }
+    Ok(hour * 60 + minute)
+}
+
+fn blackout_window_active(window: &BlackoutWindow, time_zone: &TimeZone, timestamp: i64) -> bool {
+    match window.period {
+        BlackoutPeriod::Once { starts_at, ends_at } => {
+            starts_at <= timestamp && timestamp < ends_at
+        }
+        BlackoutPeriod::Weekly {
+            days,
+            start_minute,
+            end_minute,
+        } => {
+            let Some(local) = local_minute_at(time_zone, timestamp) else {
+                return false;
+            };
+            let minute = local.hour() * 60 + local.minute();
+            let day = local.weekday().num_days_from_sunday() as usize;
+            if start_minute < end_minute {
+                days[day] && minute >= start_minute && minute < end_minute
+            } else {
+                (days[day] && minute >= start_minute)
+                    || (days[(day + 6) % 7] && minute < end_minute)
+            }
+        }
+    }
+}
+
+fn active_blackout_windows<'a>(
+    state: &'a AppState,
+    plan_windows: &'a [BlackoutWindow],
+    plan_time_zone: &TimeZone,
+    timestamp: i64,
+) -> Vec<&'a BlackoutWindow> {
+    let worker_time_zone = TimeZone::system();
+    state
+        .blackout_windows
+        .iter()
+        .filter(|window| blackout_window_active(window, &worker_time_zone, timestamp))
+        .chain(
+            plan_windows
+                .iter()
+                .filter(|window| blackout_window_active(window, plan_time_zone, timestamp)),
+        )
+        .collect()
+}
+
+async fn wait_for_hard_deadline(
+    state: &AppState,
+    plan_windows: &[BlackoutWindow],
+    plan_time_zone: &TimeZone,
+) -> String {
+    let mut interval = time::interval(Duration::from_secs(5));
+    loop {
+        interval.tick().await;
+        let now = Utc::now().timestamp();
+        if let Some(window) = active_blackout_windows(state, plan_windows, plan_time_zone, now)
+            .into_iter()
+            .find(|window| window.stop_running)
+        {
+            return window.spec.clone();
+        }
+    }
+}
+
+fn local_minute_at(time_zone: &TimeZone, timestamp: i64) -> Option<NaiveDateTime> {
+    let local = time_zone.to_datetime(Timestamp::from_second(timestamp).ok()?);
+    NaiveDate::from_ymd_opt(
+        i32::from(local.year()),
+        local.month() as u32,
+        local.day() as u32,
+    )?
+    .and_hms_opt(local.hour() as u32, local.minute() as u32, 0)
+}
+
+fn timestamp_for_local_minute(time_zone: &TimeZone, local: NaiveDateTime) -> Option<i64> {
+    let civil = jiff::civil::DateTime::new(
+        i16::try_from(local.year()).ok()?,
+        local.month() as i8,
+        local.day() as i8,
+        local.hour() as i8,
+        local.minute() as i8,
+        0,
+        0,
+    )
+    .ok()?;
+    let timestamp = time_zone.to_ambiguous_timestamp(civil).compatible().ok()?;
+    Some(timestamp.as_second())
+}
+
+fn format_timestamp(time_zone: &TimeZone, timestamp: i64) -> Option<String> {
+    let offset = time_zone.to_offset(Timestamp::from_second(timestamp).ok()?);
+    let offset = FixedOffset::east_opt(offset.seconds())?;
+    Some(
+        DateTime::from_timestamp(timestamp, 0)?
+            .with_timezone(&offset)
+            .to_rfc3339(),
+    )
+}
+
+fn stable_hash(parts: &[&[u8]]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.iter().chain(std::iter::once(&0xff)) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+fn plan_start_delay_seconds(state: &AppState, plan: &SyncedBackupPlan, scheduled_at: i64) -> i64 {
+    let jitter_seconds = plan.jitter_seconds.min(MAX_JITTER_SECONDS);
+    if jitter_seconds == 0 {
+        return 0;
+    }
+
+    let hash = match plan.jitter_mode {
+        JitterMode::Deterministic => {
+            stable_hash(&[state.bearer_token.as_bytes(), plan.id.as_bytes()])
+        }
+        JitterMode::Random => stable_hash(&[
+            &state.jitter_seed.to_le_bytes(),
+            plan.id.as_bytes(),
+            &scheduled_at.to_le_bytes(),
+        ]),
+    };
+    (hash % (u64::from(jitter_seconds) + 1)) as i64
+}
+
+fn compute_next_run_at(
+    state: &AppState,
+    plan: &SyncedBackupPlan,
+    time_zone: &TimeZone,
+) -> Option<String> {
+    let parsed = parse_cron_expression(&plan.cron).ok()?;
+    let next = *next_cron_occurrences(&parsed, time_zone, Utc::now().timestamp(), 1).first()?;
+    format_timestamp(
+        time_zone,
+        next + plan_start_delay_seconds(state, plan, next),
+    )
+}
 
-    state.requests_total.fetch_add(1, Ordering::Relaxed);
-    if status >= 500 {
-        state.error_total.fetch_add(1, Ordering::Relaxed);
+fn latest_due_tick(
+    parsed: &ParsedCron,
+    time_zone: &TimeZone,
+    last_tick: i64,
+    now_minute: i64,
+    window_minutes: u32,
+) -> Option<i64> {
+    let earliest = (last_tick + 1).max(now_minute - i64::from(window_minutes) * 60);
+    if earliest > now_minute {
+        return None;
     }
-
-    if status >= 500 {
-        log_error(format!("{method} {path} -> {status} ({elapsed}ms)"));
-    } else if status >= 400 {
-        log_warn(format!("{method} {path} -> {status} ({elapsed}ms)"));
-    } else {
-        log_info(format!("{method} {path} -> {status} ({elapsed}ms)"));
+    let slack = ChronoDuration::minutes(DST_SCAN_SLACK_MINUTES);
+    let horizon = local_minute_at(time_zone, now_minute)? + slack;
+    let mut cursor = local_minute_at(time_zone, earliest)? - slack;
+
+    let mut latest = None;
+    while let Some(next) = next_cron_match(parsed, cursor) {
+        if next > horizon {
+            break;
+        }
+        if let Some(timestamp) = timestamp_for_local_minute(time_zone, next)
+            && timestamp >= earliest
+            && timestamp <= now_minute
+            && latest < Some(timestamp)
+        {
+            latest = Some(timestamp);
+        }
+        cursor = next + ChronoDuration::minutes(1);
     }
-    response
+    latest
 }
 
-async fn sync_stats_loop(state: AppState) {
-    let mut interval = time::interval(Duration::from_secs(15));
+async fn sync_backup_plans_loop(state: AppState) {
+    let mut interval = time::interval(Duration::from_secs(30));
     let sync_url = format!(
-        "{}/api/workers/sync",
+        "{}/api/workers/backup-plans/sync",
         state.master_api_endpoint.trim_end_matches('/')
     );
 
     loop {
         interval.tick().await;
 
-        let payload = WorkerSyncPayload {
-            status: "online",
-            endpoint: state.local_api_endpoint.clone(),
-            uptime_ms: state.started_at.elapsed().as_millis() as u64,
-            requests_total: state.requests_total.load(Ordering::Relaxed),
-            error_total: state.error_total.load(Ordering::Relaxed),
+        let known_version = match state.plans_version.lock() {
+            Ok(version) => version.clone(),
+            Err(_) => {
+                log_error("failed to lock backup plan version");
+                None
+            }
         };
-
-        let response = state
+        let mut request = state
             .client
             .post(&sync_url)
-            .header("Authorization", format!("Bearer {}", state.bearer_token))
-            .json(&payload)
-            .send()
-            .await;
+            .header("Authorization", format!("Bearer {}", state.bearer_token));
+        if let Some(version) = &known_version {
+            request = request.header("If-None-Match", format!("\"{version}\""));
+        }
+        let response = request.send().await;
 
-        match response {
+        let synced = match response {
+            Ok(response) if response.status() == reqwest::StatusCode::NOT_MODIFIED => None,
             Ok(response) if response.status().is_success() => {
-                log_info(format!(
-                    "worker sync success endpoint={} status={} req={} err={}",
-                    payload.endpoint, payload.status, payload.requests_total, payload.error_total
-                ));
+                match response.json::<BackupPlanSyncResponse>().await {
+                    Ok(payload) => Some(payload),
+                    Err(error) => {
+                        log_error(format!(
+                            "failed to decode backup plan sync response: {error}"
+                        ));
+                        continue;
+                    }
+                }
             }
             Ok(response) => {
                 let status = response.status();
@@ -1735,392 +5541,987 @@ async fn sync_stats_loop(state: AppState) {
                     .text()
                     .await
                     .unwrap_or_else(|_| "unable to read response body".to_string());
-                log_error(format!("worker stats sync failed: {status} - {body}"));
+                log_error(format!("backup plan sync failed: {status} - {body}"));
+                continue;
             }
             Err(error) => {
-                log_error(format!("worker stats sync failed: {error}"));
+                log_error(format!("backup plan sync failed: {error}"));
+                continue;
+            }
+        };
+
+        let synced_at = Utc::now().timestamp();
+        let response = match synced {
+            Some(response) => {
+                match state.synced_backup_plans.lock() {
+                    Ok(mut plans) => {
+                        log_backup_plan_changes(&plans, &response.plans);
+                        *plans = response.plans.clone();
+                        log_info(format!("backup plans synced count={}", plans.len()));
+                    }
+                    Err(_) => {
+                        log_error("failed to lock synced backup plans cache");
+                        continue;
+                    }
+                }
+                if let Ok(mut version) = state.plans_version.lock() {
+                    *version = response.version.clone();
+                } else {
+                    log_error("failed to lock backup plan version");
+                }
+                response
             }
+            None => match state.synced_backup_plans.lock() {
+                Ok(plans) => BackupPlanSyncResponse {
+                    version: known_version,
+                    plans: plans.clone(),
+                },
+                Err(_) => {
+                    log_error("failed to lock synced backup plans cache");
+                    continue;
+                }
+            },
+        };
+
+        state.plans_synced_at.store(synced_at, Ordering::Relaxed);
+        save_cached_backup_plans(
+            &state.state_dir,
+            &CachedBackupPlans {
+                synced_at,
+                response,
+            },
+        );
+    }
+}
+
+fn log_backup_plan_changes(current: &[SyncedBackupPlan], synced: &[SyncedBackupPlan]) {
+    let added: Vec<&str> = synced
+        .iter()
+        .filter(|plan| !current.iter().any(|existing| existing.id == plan.id))
+        .map(|plan| plan.id.as_str())
+        .collect();
+    let changed: Vec<&str> = synced
+        .iter()
+        .filter(|plan| {
+            current
+                .iter()
+                .any(|existing| existing.id == plan.id && existing != *plan)
+        })
+        .map(|plan| plan.id.as_str())
+        .collect();
+    let removed: Vec<&str> = current
+        .iter()
+        .filter(|existing| !synced.iter().any(|plan| plan.id == existing.id))
+        .map(|plan| plan.id.as_str())
+        .collect();
+
+    if added.is_empty() && changed.is_empty() && removed.is_empty() {
+        return;
+    }
+    log_info(format!(
+        "backup plans changed added=[{}] changed=[{}] removed=[{}]",
+        added.join(","),
+        changed.join(","),
+        removed.join(",")
+    ));
+}
+
+fn plan_cache_is_stale(state: &AppState) -> bool {
+    if state.plan_cache_max_age_minutes == 0 {
+        return false;
+    }
+    let age_seconds = Utc::now().timestamp() - state.plans_synced_at.load(Ordering::Relaxed);
+    age_seconds > i64::from(state.plan_cache_max_age_minutes) * 60
+}
+
+fn enqueue_pending_report(state: &AppState, report: PendingReport) {
+    if let Ok(mut queue) = state.pending_reports.lock() {
+        if queue.len() >= PENDING_REPORTS_MAX {
+            log_warn(format!(
+                "pending reports queue full ({PENDING_REPORTS_MAX}), dropping oldest entry"
+            ));
+            queue.remove(0);
         }
+        log_info(format!(
+            "queuing pending report url={} attempt={}",
+            report.url, report.attempts
+        ));
+        queue.push(report);
+    } else {
+        log_error("failed to lock pending reports queue");
     }
 }
 
-fn extract_snapshot_ref(parsed_json: Option<&Value>) -> (Option<String>, Option<String>) {
-    let Some(value) = parsed_json else {
-        return (None, None);
+fn build_backup_report(
+    result: Result<RusticCommandResult, (StatusCode, Json<ApiErrorResponse>)>,
+    hooks: Vec<BackupHookResult>,
+    started: Instant,
+    next_run_at: Option<String>,
+) -> BackupPlanReportRequest {
+    let (run_status, error, snapshot_id, snapshot_time, output_value) = match result {
+        Ok(rustic) => {
+            let (snapshot_id, snapshot_time) = extract_snapshot_ref(rustic.parsed_json.as_ref());
+            let output = serde_json::to_value(&rustic).unwrap_or(Value::Null);
+            (
+                "success".to_string(),
+                None,
+                snapshot_id,
+                snapshot_time,
+                output,
+            )
+        }
+        Err((status, error_response)) => {
+            let error = error_response.0.error;
+            (
+                backup_failure_status(status).to_string(),
+                Some(error),
+                None,
+                None,
+                Value::Null,
+            )
+        }
     };
+    let mut report = BackupPlanReportRequest {
+        status: run_status,
+        error,
+        duration_ms: started.elapsed().as_millis() as u64,
+        snapshot_id,
+        snapshot_time,
+        next_run_at,
+        output: output_value,
+        ..Default::default()
+    };
+    if !hooks.is_empty() {
+        attach_report_output(
+            &mut report,
+            "hooks",
+            serde_json::to_value(&hooks).unwrap_or(Value::Null),
+        );
+    }
+    report
+}
 
-    let candidate = if let Some(array) = value.as_array() {
-        array.first()
+fn backup_failure_status(status: StatusCode) -> &'static str {
+    if status == StatusCode::GATEWAY_TIMEOUT {
+        "timeout"
     } else {
-        Some(value)
+        "failed"
+    }
+}
+
+async fn send_report_or_enqueue(
+    state: &AppState,
+    report_url: String,
+    payload: BackupPlanReportRequest,
+    subject: &str,
+) {
+    let should_queue = match state
+        .client
+        .post(&report_url)
+        .header("Authorization", format!("Bearer {}", state.bearer_token))
+        .json(&payload)
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            log_info(format!("{subject} reported status={}", payload.status));
+            false
+        }
+        Ok(response) => {
+            let status = response.status();
+            let body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "unable to read response body".to_string());
+            log_error(format!(
+                "{subject} report failed status={} body={} — queuing for retry",
+                status, body
+            ));
+            true
+        }
+        Err(error) => {
+            log_error(format!(
+                "{subject} report request failed error={} — queuing for retry",
+                error
+            ));
+            true
+        }
     };
 
-    let Some(object) = candidate.and_then(|entry| entry.as_object()) else {
-        return (None, None);
+    if should_queue {
+        enqueue_pending_report(
+            state,
+            PendingReport {
+                url: report_url,
+                payload,
+                attempts: 1,
+            },
+        );
+    }
+}
+
+fn admit_plan_run(state: &AppState, run: ScheduledPlanRun) -> PlanRunAdmission {
+    let Ok(mut active) = state.active_plan_runs.lock() else {
+        log_error("failed to lock active plan runs");
+        return PlanRunAdmission::Start(run);
     };
 
-    let snapshot_id = object
-        .get("snapshotId")
-        .and_then(Value::as_str)
-        .or_else(|| object.get("id").and_then(Value::as_str))
-        .map(ToOwned::to_owned);
-    let snapshot_time = object
-        .get("snapshotTime")
-        .and_then(Value::as_str)
-        .or_else(|| object.get("time").and_then(Value::as_str))
-        .map(ToOwned::to_owned);
+    let entry = active.entry(run.plan.id.clone()).or_default();
+    if entry.running == 0 || run.plan.overlap_policy == OverlapPolicy::Allow {
+        entry.running += 1;
+        return PlanRunAdmission::Start(run);
+    }
 
-    (snapshot_id, snapshot_time)
+    match run.plan.overlap_policy {
+        OverlapPolicy::QueueOne if entry.queued.is_none() => {
+            log_info(format!(
+                "previous run still in progress, queuing run plan_id={}",
+                run.plan.id
+            ));
+            entry.queued = Some(run);
+            PlanRunAdmission::Queued
+        }
+        OverlapPolicy::QueueOne => PlanRunAdmission::Skipped(
+            run,
+            "previous run is still in progress and another run is already queued".to_string(),
+        ),
+        _ => PlanRunAdmission::Skipped(run, "previous run is still in progress".to_string()),
+    }
 }
 
-fn parse_cron_field(raw: &str, min: u32, max: u32) -> Option<HashSet<u32>> {
-    let mut values = HashSet::new();
+fn finish_plan_run(state: &AppState, plan_id: &str) -> Option<ScheduledPlanRun> {
+    let Ok(mut active) = state.active_plan_runs.lock() else {
+        log_error("failed to lock active plan runs");
+        return None;
+    };
 
-    for chunk_raw in raw.split(',') {
-        let chunk = chunk_raw.trim();
-        if chunk.is_empty() {
-            return None;
+    let entry = active.get_mut(plan_id)?;
+    if let Some(next) = entry.queued.take() {
+        return Some(next);
+    }
+    entry.running = entry.running.saturating_sub(1);
+    if entry.running == 0 {
+        active.remove(plan_id);
+    }
+    None
+}
+
+async fn run_scheduled_plan(state: AppState, mut run: ScheduledPlanRun) {
+    loop {
+        let plan_id = run.plan.id.clone();
+        run_synced_backup_plan(state.clone(), run).await;
+        match finish_plan_run(&state, &plan_id) {
+            Some(next) => {
+                log_info(format!("starting queued run plan_id={plan_id}"));
+                run = next;
+            }
+            None => break,
+        }
+    }
+}
+
+async fn report_unstarted_plan_run(
+    state: &AppState,
+    run: &ScheduledPlanRun,
+    status: &str,
+    reason: String,
+) {
+    log_warn(format!(
+        "{status} run plan_id={} scheduled_at={}: {reason}",
+        run.plan.id,
+        format_timestamp(&run.time_zone, run.scheduled_at).unwrap_or_default()
+    ));
+    let report_url = format!(
+        "{}/api/workers/backup-plans/{}/report",
+        state.master_api_endpoint.trim_end_matches('/'),
+        run.plan.id
+    );
+    let payload = BackupPlanReportRequest {
+        operation: run.plan.operation,
+        status: status.to_string(),
+        error: Some(reason),
+        next_run_at: compute_next_run_at(state, &run.plan, &run.time_zone),
+        scheduled_at: format_timestamp(&run.time_zone, run.scheduled_at),
+        late: run.late,
+        output: Value::Null,
+        ..Default::default()
+    };
+    send_report_or_enqueue(
+        state,
+        report_url,
+        payload,
+        &format!("{status} run plan_id={}", run.plan.id),
+    )
+    .await;
+}
+
+async fn defer_plan_run(state: &AppState, run: ScheduledPlanRun, window: &str) {
+    let reason = format!("blackout window '{window}' is active");
+    let already_deferred = match state.deferred_plan_runs.lock() {
+        Ok(deferred) => deferred.contains_key(&run.plan.id),
+        Err(_) => {
+            log_error("failed to lock deferred plan runs");
+            return;
         }
+    };
+    if already_deferred {
+        let reason = format!("{reason} and another run is already deferred");
+        report_unstarted_plan_run(state, &run, "skipped", reason).await;
+        return;
+    }
 
-        let (base_raw, step_raw) = chunk
-            .split_once('/')
-            .map(|(base, step)| (base, Some(step)))
-            .unwrap_or((chunk, None));
-        let step = match step_raw {
-            Some(step_str) => step_str.parse::<u32>().ok()?,
-            None => 1,
-        };
-        if step == 0 {
-            return None;
+    report_unstarted_plan_run(state, &run, "deferred", reason).await;
+    if let Ok(mut deferred) = state.deferred_plan_runs.lock() {
+        deferred.entry(run.plan.id.clone()).or_insert(run);
+    } else {
+        log_error("failed to lock deferred plan runs");
+    }
+}
+
+fn take_released_plan_runs(
+    state: &AppState,
+    plans: &[SyncedBackupPlan],
+    now: i64,
+) -> Vec<ScheduledPlanRun> {
+    let Ok(mut deferred) = state.deferred_plan_runs.lock() else {
+        log_error("failed to lock deferred plan runs");
+        return Vec::new();
+    };
+
+    deferred.retain(|plan_id, _| plans.iter().any(|plan| &plan.id == plan_id));
+    let released: Vec<String> = deferred
+        .iter()
+        .filter(|(_, run)| {
+            active_blackout_windows(state, &run.blackout_windows, &run.time_zone, now).is_empty()
+        })
+        .map(|(plan_id, _)| plan_id.clone())
+        .collect();
+    released
+        .iter()
+        .filter_map(|plan_id| deferred.remove(plan_id))
+        .collect()
+}
+
+async fn run_synced_backup_plan(state: AppState, run: ScheduledPlanRun) {
+    let registered = register_operation(
+        &state,
+        Some(format!("plan-{}-{}", run.plan.id, run.scheduled_at)),
+        run.plan.operation.as_str(),
+        Some(run.plan.id.clone()),
+        run.plan.timeout_seconds,
+    )
+    .or_else(|_| {
+        register_operation(
+            &state,
+            None,
+            run.plan.operation.as_str(),
+            Some(run.plan.id.clone()),
+            run.plan.timeout_seconds,
+        )
+    });
+    let (operation, _registration) = match registered {
+        Ok(registered) => registered,
+        Err((_, error)) => {
+            log_error(format!(
+                "failed to register run plan_id={}: {}",
+                run.plan.id, error.0.error
+            ));
+            return;
         }
+    };
 
-        let mut range_min = min;
-        let mut range_max = max;
-        if base_raw != "*" {
-            if let Some((start_raw, end_raw)) = base_raw.split_once('-') {
-                range_min = start_raw.parse::<u32>().ok()?;
-                range_max = end_raw.parse::<u32>().ok()?;
-            } else {
-                let single = base_raw.parse::<u32>().ok()?;
-                range_min = single;
-                range_max = single;
+    let start_at = run.scheduled_at + plan_start_delay_seconds(&state, &run.plan, run.scheduled_at);
+    let delay = start_at - Utc::now().timestamp();
+    if delay > 0 {
+        log_info(format!(
+            "delaying run plan_id={} by {delay}s for start jitter",
+            run.plan.id
+        ));
+        sleep_unless_cancelled(&operation, Duration::from_secs(delay as u64)).await;
+    }
+    if operation_cancelled(&operation) {
+        report_unstarted_plan_run(
+            &state,
+            &run,
+            "cancelled",
+            "operation cancelled before start".to_string(),
+        )
+        .await;
+        return;
+    }
+
+    let active_window = active_blackout_windows(
+        &state,
+        &run.blackout_windows,
+        &run.time_zone,
+        Utc::now().timestamp(),
+    )
+    .first()
+    .map(|window| window.spec.clone());
+    if let Some(window) = active_window {
+        defer_plan_run(&state, run, &window).await;
+        return;
+    }
+
+    let ScheduledPlanRun {
+        plan,
+        time_zone,
+        blackout_windows,
+        scheduled_at,
+        late,
+    } = run;
+    let report_url = format!(
+        "{}/api/workers/backup-plans/{}/report",
+        state.master_api_endpoint.trim_end_matches('/'),
+        plan.id
+    );
+
+    let max_attempts = plan.retry.max_attempts.clamp(1, MAX_RETRY_ATTEMPTS);
+    let mut attempts = Vec::new();
+    let mut queued_total = Duration::ZERO;
+    let mut first_started = None;
+    let mut hooks = Vec::new();
+    let result = loop {
+        let attempt = attempts.len() as u32 + 1;
+        let queued = Instant::now();
+        let (result, started) = match acquire_operation_slot(
+            &state,
+            &operation,
+            &plan.request.repository,
+            RepositoryAccess::Exclusive,
+        )
+        .await
+        {
+            Ok(_slot) => {
+                let started = Instant::now();
+                let result = tokio::select! {
+                    (result, hook_results) = execute_plan_operation(&state, &operation, &plan) => {
+                        hooks = hook_results;
+                        result
+                    }
+                    window = wait_for_hard_deadline(&state, &blackout_windows, &time_zone) => {
+                        log_warn(format!(
+                            "stopping run plan_id={} at hard deadline of blackout window '{window}'",
+                            plan.id
+                        ));
+                        Err(api_error(
+                            StatusCode::SERVICE_UNAVAILABLE,
+                            format!(
+                                "{} stopped at hard deadline of blackout window '{window}'",
+                                plan.operation.as_str()
+                            ),
+                        ))
+                    }
+                };
+                (result, started)
+            }
+            Err(error) => (Err(error), Instant::now()),
+        };
+        queued_total += started.duration_since(queued);
+        first_started.get_or_insert(started);
+
+        let error_class = result
+            .as_ref()
+            .err()
+            .map(|(status, error)| classify_backup_error(*status, &error.0.error));
+        attempts.push(BackupAttemptReport {
+            attempt,
+            status: match &result {
+                Ok(_) => "success",
+                Err(_) if operation_cancelled(&operation) => "cancelled",
+                Err((status, _)) => backup_failure_status(*status),
             }
+            .to_string(),
+            error: result
+                .as_ref()
+                .err()
+                .map(|(_, error)| error.0.error.clone()),
+            error_class,
+            duration_ms: started.elapsed().as_millis() as u64,
+        });
+
+        let Some(error_class) = error_class else {
+            break result;
+        };
+        if attempt >= max_attempts
+            || operation_cancelled(&operation)
+            || error_class == BackupErrorClass::Hook
+            || !plan.retry.retry_on.contains(&error_class)
+        {
+            break result;
         }
 
-        if range_min < min || range_max > max || range_min > range_max {
-            return None;
+        let backoff = retry_backoff(&plan.retry, attempt);
+        log_warn(format!(
+            "{} attempt {attempt}/{max_attempts} failed plan_id={}, retrying in {}s",
+            plan.operation.as_str(),
+            plan.id,
+            backoff.as_secs()
+        ));
+        sleep_unless_cancelled(&operation, backoff).await;
+        if operation_cancelled(&operation) {
+            break result;
         }
-        let mut value = range_min;
-        while value <= range_max {
-            values.insert(value);
-            value = match value.checked_add(step) {
-                Some(next) => next,
-                None => break,
-            };
+        if !active_blackout_windows(
+            &state,
+            &blackout_windows,
+            &time_zone,
+            Utc::now().timestamp(),
+        )
+        .is_empty()
+        {
+            log_warn(format!(
+                "blackout window active, not retrying plan_id={}",
+                plan.id
+            ));
+            break result;
         }
-    }
-
-    Some(values)
-}
+    };
 
-struct ParsedCron {
-    minute: HashSet<u32>,
-    hour: HashSet<u32>,
-    day_of_month: HashSet<u32>,
-    month: HashSet<u32>,
-    day_of_week: HashSet<u32>,
-    is_day_of_month_wildcard: bool,
-    is_day_of_week_wildcard: bool,
+    let retention = match &result {
+        Ok(_) if !operation_cancelled(&operation) => {
+            apply_plan_retention(&state, &operation, &plan).await
+        }
+        _ => None,
+    };
+    let mut payload = build_backup_report(
+        result,
+        hooks,
+        first_started.unwrap_or_else(Instant::now),
+        compute_next_run_at(&state, &plan, &time_zone),
+    );
+    if let Some(retention) = retention {
+        attach_report_output(
+            &mut payload,
+            "retention",
+            serde_json::to_value(&retention).unwrap_or(Value::Null),
+        );
+    }
+    payload.operation = plan.operation;
+    payload.scheduled_at = format_timestamp(&time_zone, scheduled_at);
+    payload.late = late;
+    payload.queued_ms = queued_total.as_millis() as u64;
+    payload.attempts = attempts;
+    if operation_cancelled(&operation) {
+        payload.status = "cancelled".to_string();
+    }
+    send_report_or_enqueue(
+        &state,
+        report_url,
+        payload,
+        &format!("backup plan run plan_id={}", plan.id),
+    )
+    .await;
 }
 
-fn parse_cron_expression(cron: &str) -> Option<ParsedCron> {
-    let parts = cron.split_whitespace().collect::<Vec<_>>();
-    if parts.len() != 5 {
-        return None;
+fn classify_backup_error(status: StatusCode, error: &str) -> BackupErrorClass {
+    if status == StatusCode::BAD_REQUEST {
+        return BackupErrorClass::InvalidRequest;
+    }
+    if status == StatusCode::GATEWAY_TIMEOUT {
+        return BackupErrorClass::Timeout;
+    }
+    if status == StatusCode::FAILED_DEPENDENCY {
+        return BackupErrorClass::Hook;
     }
 
-    let minute_field = parts[0];
-    let hour_field = parts[1];
-    let day_of_month_field = parts[2];
-    let month_field = parts[3];
-    let day_of_week_field = parts[4];
+    let error = error.to_ascii_lowercase();
+    let matches_any = |markers: &[&str]| markers.iter().any(|marker| error.contains(marker));
+    if matches_any(&[
+        "repository is locked",
+        "already locked",
+        "unable to create lock",
+        "failed to lock",
+    ]) {
+        BackupErrorClass::Locked
+    } else if matches_any(&[
+        "connection refused",
+        "connection reset",
+        "connection closed",
+        "timed out",
+        "no such host",
+        "network is unreachable",
+        "temporary failure in name resolution",
+        "broken pipe",
+        "tls handshake",
+    ]) {
+        BackupErrorClass::Network
+    } else if http_status_in_error(&error).is_some_and(|code| code == 429 || code >= 500)
+        || matches_any(&[
+            "slowdown",
+            "slow down",
+            "too many requests",
+            "rate limit",
+            "throttl",
+            "service unavailable",
+        ])
+    {
+        BackupErrorClass::Backend
+    } else {
+        BackupErrorClass::Other
+    }
+}
 
-    let minute = parse_cron_field(minute_field, 0, 59)?;
-    let hour = parse_cron_field(hour_field, 0, 23)?;
-    let day_of_month = parse_cron_field(day_of_month_field, 1, 31)?;
-    let month = parse_cron_field(month_field, 1, 12)?;
-    let day_of_week = parse_cron_field(day_of_week_field, 0, 6)?;
+fn http_status_in_error(error: &str) -> Option<u16> {
+    let three_digits = |text: &str| {
+        let digits = text.get(..3)?;
+        let terminated = !text[3..].starts_with(|ch: char| ch.is_ascii_digit());
+        (digits.bytes().all(|byte| byte.is_ascii_digit()) && terminated)
+            .then(|| digits.parse::<u16>().ok())
+            .flatten()
+            .filter(|code| (100..=599).contains(code))
+    };
 
-    Some(ParsedCron {
-        minute,
-        hour,
-        day_of_month,
-        month,
-        day_of_week,
-        is_day_of_month_wildcard: day_of_month_field == "*",
-        is_day_of_week_wildcard: day_of_week_field == "*",
+    [
+        "status code",
+        "statuscode",
+        "status",
+        "http error",
+        "http",
+        "client error (",
+        "server error (",
+    ]
+    .into_iter()
+    .find_map(|marker| {
+        error.match_indices(marker).find_map(|(index, _)| {
+            three_digits(error[index + marker.len()..].trim_start_matches([' ', ':', '=']))
+        })
     })
 }
 
-fn cron_matches_now(parsed: &ParsedCron) -> bool {
-    let now = Local::now();
-    let minute = now.minute();
-    let hour = now.hour();
-    let day_of_month = now.day();
-    let month = now.month();
-    let day_of_week = now.weekday().num_days_from_sunday();
-
-    if !parsed.minute.contains(&minute)
-        || !parsed.hour.contains(&hour)
-        || !parsed.month.contains(&month)
-    {
-        return false;
-    }
-
-    let dom_match = parsed.day_of_month.contains(&day_of_month);
-    let dow_match = parsed.day_of_week.contains(&day_of_week);
-    if parsed.is_day_of_month_wildcard && parsed.is_day_of_week_wildcard {
-        return true;
-    }
-    if parsed.is_day_of_month_wildcard {
-        return dow_match;
-    }
-    if parsed.is_day_of_week_wildcard {
-        return dom_match;
-    }
-
-    dom_match || dow_match
+fn retry_backoff(retry: &RetryPolicy, attempt: u32) -> Duration {
+    let factor = 1u64 << attempt.saturating_sub(1).min(20);
+    Duration::from_secs(
+        retry
+            .initial_backoff_seconds
+            .saturating_mul(factor)
+            .min(retry.max_backoff_seconds),
+    )
 }
 
-fn compute_next_run_at(cron: &str) -> Option<String> {
-    let parsed = parse_cron_expression(cron)?;
-    let mut cursor = Local::now() + ChronoDuration::minutes(1);
-    cursor = cursor
-        .with_second(0)?
-        .with_nanosecond(0)?;
-    for _ in 0..(60 * 24 * 366) {
-        let minute = cursor.minute();
-        let hour = cursor.hour();
-        let day_of_month = cursor.day();
-        let month = cursor.month();
-        let day_of_week = cursor.weekday().num_days_from_sunday();
-
-        if parsed.minute.contains(&minute)
-            && parsed.hour.contains(&hour)
-            && parsed.month.contains(&month)
-        {
-            let dom_match = parsed.day_of_month.contains(&day_of_month);
-            let dow_match = parsed.day_of_week.contains(&day_of_week);
-            let matches = if parsed.is_day_of_month_wildcard && parsed.is_day_of_week_wildcard {
-                true
-            } else if parsed.is_day_of_month_wildcard {
-                dow_match
-            } else if parsed.is_day_of_week_wildcard {
-                dom_match
-            } else {
-                dom_match || dow_match
-            };
-            if matches {
-                return Some(cursor.to_rfc3339());
+async fn report_invalid_plan_schedule(state: &AppState, plan: &SyncedBackupPlan, error: String) {
+    let report_key = format!(
+        "{}:{}:{}:{}",
+        plan.id,
+        plan.cron,
+        plan.time_zone.as_deref().unwrap_or_default(),
+        plan.blackout_windows.join("|")
+    );
+    match state.reported_invalid_schedules.lock() {
+        Ok(mut reported) => {
+            if !reported.insert(report_key) {
+                return;
             }
         }
-        cursor += ChronoDuration::minutes(1);
+        Err(_) => {
+            log_error("failed to lock invalid schedule report cache");
+            return;
+        }
     }
-    None
+
+    log_warn(format!("plan {} has {error}", plan.id));
+    let report_url = format!(
+        "{}/api/workers/backup-plans/{}/report",
+        state.master_api_endpoint.trim_end_matches('/'),
+        plan.id
+    );
+    let payload = BackupPlanReportRequest {
+        operation: plan.operation,
+        status: "failed".to_string(),
+        error: Some(error),
+        duration_ms: 0,
+        snapshot_id: None,
+        snapshot_time: None,
+        output: Value::Null,
+        ..Default::default()
+    };
+    send_report_or_enqueue(
+        state,
+        report_url,
+        payload,
+        &format!("invalid schedule for plan_id={}", plan.id),
+    )
+    .await;
 }
 
-async fn sync_backup_plans_loop(state: AppState) {
-    let mut interval = time::interval(Duration::from_secs(30));
-    let sync_url = format!(
-        "{}/api/workers/backup-plans/sync",
-        state.master_api_endpoint.trim_end_matches('/')
+async fn run_claimed_backup_run(state: AppState, run: ClaimedBackupRun) {
+    let plan = state
+        .synced_backup_plans
+        .lock()
+        .ok()
+        .and_then(|plans| plans.iter().find(|plan| plan.id == run.plan_id).cloned())
+        .map(|plan| SyncedBackupPlan {
+            request: run.request.clone(),
+            ..plan
+        });
+    let plan_operation = plan
+        .as_ref()
+        .map_or(PlanOperation::Backup, |plan| plan.operation);
+    let kind = plan_operation.as_str();
+    let (operation, _registration) = match register_operation(
+        &state,
+        Some(run.id.clone()),
+        kind,
+        Some(run.plan_id.clone()),
+        plan.as_ref().and_then(|plan| plan.timeout_seconds),
+    ) {
+        Ok(registered) => registered,
+        Err((_, error)) => {
+            log_error(format!(
+                "failed to register claimed backup run run_id={}: {}",
+                run.id, error.0.error
+            ));
+            return;
+        }
+    };
+    let complete_url = format!(
+        "{}/api/workers/backup-runs/{}/complete",
+        state.master_api_endpoint.trim_end_matches('/'),
+        run.id
     );
+    log_info(format!(
+        "executing claimed {kind} run run_id={} plan_id={}",
+        run.id, run.plan_id
+    ));
 
-    loop {
-        interval.tick().await;
+    let queued = Instant::now();
+    let (result, hooks, started) = match acquire_operation_slot(
+        &state,
+        &operation,
+        &run.request.repository,
+        RepositoryAccess::Exclusive,
+    )
+    .await
+    {
+        Ok(_slot) => {
+            let started = Instant::now();
+            let (result, hooks) = match &plan {
+                Some(plan) => execute_plan_operation(&state, &operation, plan).await,
+                None => execute_backup_request(&state, &operation, run.request).await,
+            };
+            (result, hooks, started)
+        }
+        Err(error) => (Err(error), Vec::new(), Instant::now()),
+    };
+    let retention = match (&result, plan) {
+        (Ok(_), Some(plan)) if !operation_cancelled(&operation) => {
+            apply_plan_retention(&state, &operation, &plan).await
+        }
+        _ => None,
+    };
+    let mut payload = build_backup_report(result, hooks, started, None);
+    if let Some(retention) = retention {
+        attach_report_output(
+            &mut payload,
+            "retention",
+            serde_json::to_value(&retention).unwrap_or(Value::Null),
+        );
+    }
+    payload.operation = plan_operation;
+    payload.queued_ms = started.duration_since(queued).as_millis() as u64;
+    if operation_cancelled(&operation) {
+        payload.status = "cancelled".to_string();
+    }
+    send_report_or_enqueue(
+        &state,
+        complete_url,
+        payload,
+        &format!(
+            "claimed backup run run_id={} plan_id={}",
+            run.id, run.plan_id
+        ),
+    )
+    .await;
+}
 
-        let response = state
-            .client
-            .post(&sync_url)
-            .header("Authorization", format!("Bearer {}", state.bearer_token))
-            .send()
-            .await;
+fn pending_reports_path(state_dir: &str) -> PathBuf {
+    PathBuf::from(state_dir).join("pending_reports.json")
+}
 
-        let synced_plans = match response {
-            Ok(response) if response.status().is_success() => {
-                match response.json::<BackupPlanSyncResponse>().await {
-                    Ok(payload) => payload.plans,
-                    Err(error) => {
-                        log_error(format!("failed to decode backup plan sync response: {error}"));
-                        continue;
-                    }
+fn load_pending_reports(state_dir: &str) -> Vec<PendingReport> {
+    let path = pending_reports_path(state_dir);
+    match fs::read_to_string(&path) {
+        Ok(data) => match serde_json::from_str::<Vec<PendingReport>>(&data) {
+            Ok(reports) => {
+                if !reports.is_empty() {
+                    log_info(format!(
+                        "loaded {} pending reports from {}",
+                        reports.len(),
+                        path.display()
+                    ));
                 }
+                reports
             }
-            Ok(response) => {
-                let status = response.status();
-                let body = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "unable to read response body".to_string());
-                log_error(format!("backup plan sync failed: {status} - {body}"));
-                continue;
-            }
-            Err(error) => {
-                log_error(format!("backup plan sync failed: {error}"));
-                continue;
+            Err(err) => {
+                log_warn(format!(
+                    "failed to parse pending reports from {}: {err}",
+                    path.display()
+                ));
+                Vec::new()
             }
-        };
-
-        if let Ok(mut plans) = state.synced_backup_plans.lock() {
-            *plans = synced_plans;
-            log_info(format!("backup plans synced count={}", plans.len()));
-        } else {
-            log_error("failed to lock synced backup plans cache");
-        }
+        },
+        Err(_) => Vec::new(),
     }
 }
 
-fn enqueue_pending_report(state: &AppState, report: PendingReport) {
-    if let Ok(mut queue) = state.pending_reports.lock() {
-        if queue.len() >= PENDING_REPORTS_MAX {
-            log_warn(format!(
-                "pending reports queue full ({PENDING_REPORTS_MAX}), dropping oldest entry"
-            ));
-            queue.remove(0);
+fn save_pending_reports(state_dir: &str, reports: &[PendingReport]) {
+    let path = pending_reports_path(state_dir);
+    let _ = fs::create_dir_all(state_dir);
+    match serde_json::to_string(reports) {
+        Ok(json) => {
+            if let Err(err) = fs::write(&path, json) {
+                log_warn(format!(
+                    "failed to persist pending reports to {}: {err}",
+                    path.display()
+                ));
+            }
+        }
+        Err(err) => {
+            log_warn(format!("failed to serialize pending reports: {err}"));
         }
-        log_info(format!(
-            "queuing pending report url={} attempt={}",
-            report.url, report.attempts
-        ));
-        queue.push(report);
-    } else {
-        log_error("failed to lock pending reports queue");
     }
 }
 
-async fn run_synced_backup_plan(state: AppState, plan: SyncedBackupPlan) {
-    let started = Instant::now();
-    let report_url = format!(
-        "{}/api/workers/backup-plans/{}/report",
-        state.master_api_endpoint.trim_end_matches('/'),
-        plan.id
-    );
-
-    let (run_status, error, snapshot_id, snapshot_time, output_value) =
-        match execute_backup_request(&state, plan.request).await {
-            Ok(rustic) => {
-                let (snapshot_id, snapshot_time) = extract_snapshot_ref(rustic.parsed_json.as_ref());
-                let output = serde_json::to_value(&rustic).unwrap_or_else(|_| Value::Null);
-                ("success".to_string(), None, snapshot_id, snapshot_time, output)
-            }
-            Err((_, error_response)) => {
-                let error = error_response.0.error;
-                ("failed".to_string(), Some(error), None, None, Value::Null)
-            }
-        };
-
-    let payload = BackupPlanReportRequest {
-        status: run_status,
-        error,
-        duration_ms: started.elapsed().as_millis() as u64,
-        snapshot_id,
-        snapshot_time,
-        next_run_at: compute_next_run_at(&plan.cron),
-        output: output_value,
-    };
+fn cached_backup_plans_path(state_dir: &str) -> PathBuf {
+    PathBuf::from(state_dir).join("backup_plans.json")
+}
 
-    let should_queue = match state
-        .client
-        .post(&report_url)
-        .header("Authorization", format!("Bearer {}", state.bearer_token))
-        .json(&payload)
-        .send()
-        .await
-    {
-        Ok(response) if response.status().is_success() => {
+fn load_cached_backup_plans(state_dir: &str) -> Option<CachedBackupPlans> {
+    let path = cached_backup_plans_path(state_dir);
+    let data = fs::read_to_string(&path).ok()?;
+    match serde_json::from_str::<CachedBackupPlans>(&data) {
+        Ok(cached) => {
             log_info(format!(
-                "backup plan run reported plan_id={} status={}",
-                plan.id, payload.status
+                "loaded {} cached backup plans from {} synced_at={}",
+                cached.response.plans.len(),
+                path.display(),
+                DateTime::from_timestamp(cached.synced_at, 0)
+                    .map(|synced_at| synced_at.to_rfc3339())
+                    .unwrap_or_default()
             ));
-            false
+            Some(cached)
         }
-        Ok(response) => {
-            let status = response.status();
-            let body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "unable to read response body".to_string());
-            log_error(format!(
-                "backup plan report failed plan_id={} status={} body={} — queuing for retry",
-                plan.id, status, body
+        Err(err) => {
+            log_warn(format!(
+                "failed to parse cached backup plans from {}: {err}",
+                path.display()
             ));
-            true
+            None
         }
-        Err(error) => {
-            log_error(format!(
-                "backup plan report request failed plan_id={} error={} — queuing for retry",
-                plan.id, error
-            ));
-            true
+    }
+}
+
+fn save_cached_backup_plans(state_dir: &str, cached: &CachedBackupPlans) {
+    let path = cached_backup_plans_path(state_dir);
+    let tmp_path = path.with_extension("json.tmp");
+    let _ = fs::create_dir_all(state_dir);
+    let json = match serde_json::to_vec(cached) {
+        Ok(json) => json,
+        Err(err) => {
+            log_warn(format!("failed to serialize cached backup plans: {err}"));
+            return;
         }
     };
 
-    if should_queue {
-        enqueue_pending_report(
-            &state,
-            PendingReport {
-                url: report_url,
-                payload,
-                attempts: 1,
-            },
-        );
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let result = options
+        .open(&tmp_path)
+        .and_then(|mut file| file.write_all(&json))
+        .and_then(|_| fs::rename(&tmp_path, &path));
+    if let Err(err) = result {
+        log_warn(format!(
+            "failed to persist cached backup plans to {}: {err}",
+            path.display()
+        ));
     }
 }
 
-fn pending_reports_path(state_dir: &str) -> PathBuf {
-    PathBuf::from(state_dir).join("pending_reports.json")
+fn plan_ticks_path(state_dir: &str) -> PathBuf {
+    PathBuf::from(state_dir).join("plan_ticks.json")
+}
+
+fn plan_prunes_path(state_dir: &str) -> PathBuf {
+    PathBuf::from(state_dir).join("plan_prunes.json")
+}
+
+fn load_plan_ticks(state_dir: &str) -> HashMap<String, i64> {
+    load_plan_timestamps(
+        plan_ticks_path(state_dir),
+        "last executed ticks",
+        "plan ticks",
+    )
+}
+
+fn save_plan_ticks(state_dir: &str, ticks: &HashMap<String, i64>) {
+    save_plan_timestamps(state_dir, plan_ticks_path(state_dir), ticks, "plan ticks");
 }
 
-fn load_pending_reports(state_dir: &str) -> Vec<PendingReport> {
-    let path = pending_reports_path(state_dir);
+fn load_plan_prunes(state_dir: &str) -> HashMap<String, i64> {
+    load_plan_timestamps(
+        plan_prunes_path(state_dir),
+        "last prune times",
+        "plan prune times",
+    )
+}
+
+fn save_plan_prunes(state_dir: &str, prunes: &HashMap<String, i64>) {
+    save_plan_timestamps(
+        state_dir,
+        plan_prunes_path(state_dir),
+        prunes,
+        "plan prune times",
+    );
+}
+
+fn load_plan_timestamps(path: PathBuf, description: &str, name: &str) -> HashMap<String, i64> {
     match fs::read_to_string(&path) {
-        Ok(data) => match serde_json::from_str::<Vec<PendingReport>>(&data) {
-            Ok(reports) => {
-                if !reports.is_empty() {
+        Ok(data) => match serde_json::from_str::<HashMap<String, i64>>(&data) {
+            Ok(timestamps) => {
+                if !timestamps.is_empty() {
                     log_info(format!(
-                        "loaded {} pending reports from {}",
-                        reports.len(),
+                        "loaded {description} for {} plans from {}",
+                        timestamps.len(),
                         path.display()
                     ));
                 }
-                reports
+                timestamps
             }
             Err(err) => {
                 log_warn(format!(
-                    "failed to parse pending reports from {}: {err}",
+                    "failed to parse {name} from {}: {err}",
                     path.display()
                 ));
-                Vec::new()
+                HashMap::new()
             }
         },
-        Err(_) => Vec::new(),
+        Err(_) => HashMap::new(),
     }
 }
 
-fn save_pending_reports(state_dir: &str, reports: &[PendingReport]) {
-    let path = pending_reports_path(state_dir);
+fn save_plan_timestamps(
+    state_dir: &str,
+    path: PathBuf,
+    timestamps: &HashMap<String, i64>,
+    name: &str,
+) {
     let _ = fs::create_dir_all(state_dir);
-    match serde_json::to_string(reports) {
+    match serde_json::to_string(timestamps) {
         Ok(json) => {
             if let Err(err) = fs::write(&path, json) {
                 log_warn(format!(
-                    "failed to persist pending reports to {}: {err}",
+                    "failed to persist {name} to {}: {err}",
                     path.display()
                 ));
             }
         }
         Err(err) => {
-            log_warn(format!("failed to serialize pending reports: {err}"));
+            log_warn(format!("failed to serialize {name}: {err}"));
         }
     }
 }
@@ -2131,10 +6532,7 @@ async fn flush_pending_reports_loop(state: AppState) {
         interval.tick().await;
 
         let items: Vec<PendingReport> = match state.pending_reports.lock() {
-            Ok(mut queue) => {
-                let taken = queue.drain(..).collect();
-                taken
-            }
+            Ok(mut queue) => queue.drain(..).collect(),
             Err(_) => {
                 log_error("failed to lock pending reports queue for flush");
                 continue;
@@ -2209,6 +6607,7 @@ async fn flush_pending_reports_loop(state: AppState) {
 
 async fn execute_synced_backup_plans_loop(state: AppState) {
     let mut interval = time::interval(Duration::from_secs(15));
+    let mut stale_logged = false;
     loop {
         interval.tick().await;
 
@@ -2224,38 +6623,180 @@ async fn execute_synced_backup_plans_loop(state: AppState) {
             continue;
         }
 
-        for plan in plans {
-            let parsed = match parse_cron_expression(&plan.cron) {
-                Some(parsed) => parsed,
-                None => {
-                    log_warn(format!("invalid cron for plan {}: {}", plan.id, plan.cron));
+        if plan_cache_is_stale(&state) {
+            if !stale_logged {
+                log_warn(format!(
+                    "backup plans not synced for more than {} minutes, pausing scheduled runs",
+                    state.plan_cache_max_age_minutes
+                ));
+                stale_logged = true;
+            }
+            continue;
+        }
+        stale_logged = false;
+
+        let now_minute = Utc::now().timestamp().div_euclid(60) * 60;
+        let released_runs = take_released_plan_runs(&state, &plans, Utc::now().timestamp());
+        let mut due_plans = Vec::new();
+        let mut invalid_plans = Vec::new();
+        if let Ok(mut ticks) = state.plan_last_ticks.lock() {
+            let tracked_before = ticks.len();
+            ticks.retain(|plan_id, _| plans.iter().any(|plan| &plan.id == plan_id));
+            let mut changed = ticks.len() != tracked_before;
+            for plan in plans {
+                let parsed = match parse_cron_expression(&plan.cron) {
+                    Ok(parsed) => parsed,
+                    Err(reason) => {
+                        let error = format!("invalid cron '{}': {reason}", plan.cron);
+                        invalid_plans.push((plan, error));
+                        continue;
+                    }
+                };
+                let time_zone = match resolve_plan_time_zone(plan.time_zone.as_deref()) {
+                    Ok(time_zone) => time_zone,
+                    Err(reason) => {
+                        invalid_plans.push((plan, format!("invalid time zone: {reason}")));
+                        continue;
+                    }
+                };
+                let blackout_windows = match parse_blackout_windows(&plan.blackout_windows) {
+                    Ok(blackout_windows) => blackout_windows,
+                    Err(error) => {
+                        invalid_plans.push((plan, error));
+                        continue;
+                    }
+                };
+
+                let last_tick = ticks.get(&plan.id).copied().unwrap_or(now_minute - 60);
+                let Some(due) = latest_due_tick(
+                    &parsed,
+                    &time_zone,
+                    last_tick,
+                    now_minute,
+                    state.catch_up_window_minutes,
+                ) else {
                     continue;
-                }
-            };
-            if !cron_matches_now(&parsed) {
-                continue;
+                };
+
+                ticks.insert(plan.id.clone(), due);
+                changed = true;
+                due_plans.push((plan, time_zone, blackout_windows, due));
+            }
+
+            if changed {
+                save_plan_ticks(&state.state_dir, &ticks);
+            }
+        } else {
+            log_error("failed to lock plan tick cache");
+        }
+
+        for (plan, error) in invalid_plans {
+            report_invalid_plan_schedule(&state, &plan, error).await;
+        }
+
+        let mut runs = Vec::new();
+        for mut run in released_runs {
+            log_info(format!(
+                "blackout window ended, starting deferred run plan_id={} scheduled_at={}",
+                run.plan.id,
+                format_timestamp(&run.time_zone, run.scheduled_at).unwrap_or_default()
+            ));
+            run.late = true;
+            runs.push(run);
+        }
+        for (plan, time_zone, blackout_windows, due) in due_plans {
+            let late = due < now_minute;
+            if late {
+                log_warn(format!(
+                    "catching up missed run plan_id={} scheduled_at={} late_by_minutes={}",
+                    plan.id,
+                    format_timestamp(&time_zone, due).unwrap_or_default(),
+                    (now_minute - due) / 60
+                ));
             }
 
-            let minute_tick_key = format!("{}:{}", plan.id, Local::now().format("%Y%m%d%H%M"));
-            let mut should_run = false;
-            if let Ok(mut executed) = state.executed_plan_ticks.lock() {
-                if executed.len() > 20_000 {
-                    executed.clear();
+            runs.push(ScheduledPlanRun {
+                plan,
+                time_zone,
+                blackout_windows,
+                scheduled_at: due,
+                late,
+            });
+        }
+
+        for run in runs {
+            match admit_plan_run(&state, run) {
+                PlanRunAdmission::Start(run) => {
+                    let run_state = state.clone();
+                    tokio::spawn(async move {
+                        run_scheduled_plan(run_state, run).await;
+                    });
                 }
-                if !executed.contains(&minute_tick_key) {
-                    executed.insert(minute_tick_key);
-                    should_run = true;
+                PlanRunAdmission::Queued => {}
+                PlanRunAdmission::Skipped(run, reason) => {
+                    report_unstarted_plan_run(&state, &run, "skipped", reason).await;
                 }
-            } else {
-                log_error("failed to lock executed plan tick cache");
             }
-            if !should_run {
+        }
+    }
+}
+
+async fn claim_backup_runs_loop(state: AppState) {
+    let mut interval = time::interval(Duration::from_secs(10));
+    let claim_url = format!(
+        "{}/api/workers/backup-runs/claim",
+        state.master_api_endpoint.trim_end_matches('/')
+    );
+
+    loop {
+        interval.tick().await;
+
+        let response = state
+            .client
+            .post(&claim_url)
+            .header("Authorization", format!("Bearer {}", state.bearer_token))
+            .json(&ClaimBackupRunsRequest {
+                limit: CLAIM_BACKUP_RUNS_LIMIT,
+            })
+            .send()
+            .await;
+
+        let claimed_runs = match response {
+            Ok(response) if response.status().is_success() => {
+                match response.json::<ClaimBackupRunsResponse>().await {
+                    Ok(payload) => payload.runs,
+                    Err(error) => {
+                        log_error(format!(
+                            "failed to decode backup run claim response: {error}"
+                        ));
+                        continue;
+                    }
+                }
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "unable to read response body".to_string());
+                log_error(format!("backup run claim failed: {status} - {body}"));
+                continue;
+            }
+            Err(error) => {
+                log_error(format!("backup run claim failed: {error}"));
                 continue;
             }
+        };
 
+        if claimed_runs.is_empty() {
+            continue;
+        }
+
+        log_info(format!("claimed backup runs count={}", claimed_runs.len()));
+        for run in claimed_runs {
             let run_state = state.clone();
             tokio::spawn(async move {
-                run_synced_backup_plan(run_state, plan).await;
+                run_claimed_backup_run(run_state, run).await;
             });
         }
     }
@@ -2267,6 +6808,13 @@ struct CliArgs {
     api_token: String,
     rustic_bin: String,
     state_dir: String,
+    catch_up_window_minutes: u32,
+    max_concurrent_operations: usize,
+    plan_cache_max_age_minutes: u32,
+    blackout_windows: Vec<BlackoutWindow>,
+    command_timeout_seconds: u64,
+    default_host: Option<String>,
+    host_from_worker_id: bool,
 }
 
 fn parse_cli_args() -> Result<CliArgs, String> {
@@ -2275,6 +6823,13 @@ fn parse_cli_args() -> Result<CliArgs, String> {
     let mut api_token: Option<String> = None;
     let mut rustic_bin = "rustic".to_string();
     let mut state_dir = ".glare-worker".to_string();
+    let mut catch_up_window_minutes = DEFAULT_CATCH_UP_WINDOW_MINUTES;
+    let mut max_concurrent_operations = DEFAULT_MAX_CONCURRENT_OPERATIONS;
+    let mut plan_cache_max_age_minutes = DEFAULT_PLAN_CACHE_MAX_AGE_MINUTES;
+    let mut blackout_windows = Vec::new();
+    let mut command_timeout_seconds = DEFAULT_COMMAND_TIMEOUT_SECONDS;
+    let mut default_host: Option<String> = None;
+    let mut host_from_worker_id = false;
     let mut args = env::args().skip(1);
 
     while let Some(arg) = args.next() {
@@ -2298,6 +6853,30 @@ fn parse_cli_args() -> Result<CliArgs, String> {
             state_dir = value.to_string();
             continue;
         }
+        if let Some(value) = arg.strip_prefix("--catch-up-window-minutes=") {
+            catch_up_window_minutes = parse_cli_u32("catch-up-window-minutes", value)?;
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--command-timeout-seconds=") {
+            command_timeout_seconds = u64::from(parse_cli_u32("command-timeout-seconds", value)?);
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--default-host=") {
+            default_host = Some(parse_cli_default_host(value)?);
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--blackout-window=") {
+            blackout_windows.push(parse_cli_blackout_window(value)?);
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--plan-cache-max-age-minutes=") {
+            plan_cache_max_age_minutes = parse_cli_u32("plan-cache-max-age-minutes", value)?;
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--max-concurrent-operations=") {
+            max_concurrent_operations = parse_cli_u32("max-concurrent-operations", value)? as usize;
+            continue;
+        }
 
         match arg.as_str() {
             "--master-api-endpoint" => {
@@ -2328,6 +6907,45 @@ fn parse_cli_args() -> Result<CliArgs, String> {
                     .next()
                     .ok_or_else(|| "missing value for --state-dir".to_string())?;
             }
+            "--catch-up-window-minutes" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "missing value for --catch-up-window-minutes".to_string())?;
+                catch_up_window_minutes = parse_cli_u32("catch-up-window-minutes", &value)?;
+            }
+            "--max-concurrent-operations" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "missing value for --max-concurrent-operations".to_string())?;
+                max_concurrent_operations =
+                    parse_cli_u32("max-concurrent-operations", &value)? as usize;
+            }
+            "--plan-cache-max-age-minutes" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "missing value for --plan-cache-max-age-minutes".to_string())?;
+                plan_cache_max_age_minutes = parse_cli_u32("plan-cache-max-age-minutes", &value)?;
+            }
+            "--command-timeout-seconds" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "missing value for --command-timeout-seconds".to_string())?;
+                command_timeout_seconds =
+                    u64::from(parse_cli_u32("command-timeout-seconds", &value)?);
+            }
+            "--default-host" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "missing value for --default-host".to_string())?;
+                default_host = Some(parse_cli_default_host(&value)?);
+            }
+            "--host-from-worker-id" => host_from_worker_id = true,
+            "--blackout-window" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "missing value for --blackout-window".to_string())?;
+                blackout_windows.push(parse_cli_blackout_window(&value)?);
+            }
             _ => return Err(usage(format!("unknown argument: {arg}"))),
         }
     }
@@ -2337,6 +6955,9 @@ fn parse_cli_args() -> Result<CliArgs, String> {
     let local_api_endpoint =
         local_api_endpoint.ok_or_else(|| usage("missing required --local-api-endpoint"))?;
     let api_token = api_token.ok_or_else(|| usage("missing required --api-token"))?;
+    if max_concurrent_operations == 0 {
+        return Err(usage("--max-concurrent-operations must be at least 1"));
+    }
 
     Ok(CliArgs {
         master_api_endpoint,
@@ -2344,9 +6965,34 @@ fn parse_cli_args() -> Result<CliArgs, String> {
         api_token,
         rustic_bin,
         state_dir,
+        catch_up_window_minutes,
+        max_concurrent_operations,
+        plan_cache_max_age_minutes,
+        blackout_windows,
+        command_timeout_seconds,
+        default_host,
+        host_from_worker_id,
     })
 }
 
+fn parse_cli_default_host(value: &str) -> Result<String, String> {
+    validate_backup_host(value)
+        .map(|_| value.trim().to_string())
+        .map_err(|reason| usage(format!("invalid --default-host '{value}': {reason}")))
+}
+
+fn parse_cli_u32(flag_name: &str, value: &str) -> Result<u32, String> {
+    value
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| usage(format!("invalid --{flag_name}: {value}")))
+}
+
+fn parse_cli_blackout_window(value: &str) -> Result<BlackoutWindow, String> {
+    parse_blackout_window(value)
+        .map_err(|reason| usage(format!("invalid --blackout-window '{value}': {reason}")))
+}
+
 fn endpoint_to_socket_addr(flag_name: &str, endpoint: &str) -> Result<SocketAddr, String> {
     let without_scheme = endpoint
         .strip_prefix("http://")
@@ -2367,7 +7013,993 @@ fn endpoint_to_socket_addr(flag_name: &str, endpoint: &str) -> Result<SocketAddr
 
 fn usage(msg: impl AsRef<str>) -> String {
     format!(
-        "{}\nusage: worker --master-api-endpoint <url> --local-api-endpoint <url> --api-token <token> [--rustic-bin <path>] [--state-dir <path>]",
+        "{}\nusage: worker --master-api-endpoint <url> --local-api-endpoint <url> --api-token <token> [--rustic-bin <path>] [--state-dir <path>] [--catch-up-window-minutes <n>] [--max-concurrent-operations <n>] [--plan-cache-max-age-minutes <n>] [--blackout-window <spec>]... [--command-timeout-seconds <n>] [--default-host <name>] [--host-from-worker-id]",
         msg.as_ref()
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(raw: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn timestamp(raw: &str) -> i64 {
+        raw.parse::<Timestamp>().unwrap().as_second()
+    }
+
+    fn local(time_zone: &TimeZone, timestamp: i64) -> String {
+        Timestamp::from_second(timestamp)
+            .unwrap()
+            .to_zoned(time_zone.clone())
+            .strftime("%Y-%m-%d %H:%M %:z")
+            .to_string()
+    }
+
+    #[test]
+    fn latest_due_tick_respects_last_tick_and_catch_up_window() {
+        let hourly = parse_cron_expression("0 * * * *").unwrap();
+        let cases = [
+            (
+                "2026-10-17T09:00:00Z",
+                "2026-10-17T12:30:00Z",
+                60,
+                Some("2026-10-17T12:00:00Z"),
+            ),
+            (
+                "2026-10-17T09:00:00Z",
+                "2026-10-17T12:30:00Z",
+                600,
+                Some("2026-10-17T12:00:00Z"),
+            ),
+            ("2026-10-17T09:00:00Z", "2026-10-17T12:30:00Z", 20, None),
+            ("2026-10-17T12:00:00Z", "2026-10-17T12:30:00Z", 60, None),
+            (
+                "2026-10-17T11:00:00Z",
+                "2026-10-17T12:00:00Z",
+                0,
+                Some("2026-10-17T12:00:00Z"),
+            ),
+            (
+                "2026-10-17T11:59:00Z",
+                "2026-10-17T12:00:00Z",
+                30,
+                Some("2026-10-17T12:00:00Z"),
+            ),
+            ("2026-10-17T11:00:00Z", "2026-10-17T11:59:00Z", 1440, None),
+        ];
+        for (last_tick, now, window, expected) in cases {
+            let due = latest_due_tick(
+                &hourly,
+                &TimeZone::UTC,
+                timestamp(last_tick),
+                timestamp(now),
+                window,
+            );
+            assert_eq!(
+                due,
+                expected.map(timestamp),
+                "last tick {last_tick}, now {now}, window {window}"
+            );
+        }
+    }
+
+    #[test]
+    fn latest_due_tick_handles_dst_transitions() {
+        let berlin = resolve_plan_time_zone(Some("Europe/Berlin")).unwrap();
+        let cases = [
+            (
+                "30 2 * * *",
+                "2026-03-28T02:30:00+01:00",
+                "2026-03-29T03:29:00+02:00",
+                None,
+            ),
+            (
+                "30 2 * * *",
+                "2026-03-28T02:30:00+01:00",
+                "2026-03-29T03:30:00+02:00",
+                Some("2026-03-29T03:30:00+02:00"),
+            ),
+            (
+                "30 2 * * *",
+                "2026-03-28T02:30:00+01:00",
+                "2026-03-29T04:00:00+02:00",
+                Some("2026-03-29T03:30:00+02:00"),
+            ),
+            (
+                "30 3 * * *",
+                "2026-03-28T03:30:00+01:00",
+                "2026-03-29T03:30:00+02:00",
+                Some("2026-03-29T03:30:00+02:00"),
+            ),
+            (
+                "30 2 * * *",
+                "2026-10-24T02:30:00+02:00",
+                "2026-10-25T02:30:00+02:00",
+                Some("2026-10-25T02:30:00+02:00"),
+            ),
+            (
+                "30 2 * * *",
+                "2026-10-25T02:30:00+02:00",
+                "2026-10-25T02:30:00+01:00",
+                None,
+            ),
+            (
+                "0 * * * *",
+                "2026-10-25T02:00:00+02:00",
+                "2026-10-25T02:59:00+01:00",
+                None,
+            ),
+            (
+                "0 * * * *",
+                "2026-10-25T02:00:00+02:00",
+                "2026-10-25T03:00:00+01:00",
+                Some("2026-10-25T03:00:00+01:00"),
+            ),
+        ];
+        for (cron, last_tick, now, expected) in cases {
+            let parsed = parse_cron_expression(cron).unwrap();
+            let due = latest_due_tick(&parsed, &berlin, timestamp(last_tick), timestamp(now), 120);
+            assert_eq!(
+                due,
+                expected.map(timestamp),
+                "{cron}, last tick {last_tick}, now {now}"
+            );
+        }
+    }
+
+    #[test]
+    fn next_cron_match_handles_special_day_rules() {
+        let cases = [
+            ("0 0 L * *", "2026-01-31 00:01", "2026-02-28 00:00"),
+            ("0 0 L * *", "2028-01-31 00:01", "2028-02-29 00:00"),
+            ("0 0 l * *", "2026-04-01 00:00", "2026-04-30 00:00"),
+            ("0 0 L-3 * *", "2026-02-01 00:00", "2026-02-25 00:00"),
+            ("0 0 LW * *", "2026-05-01 00:00", "2026-05-29 00:00"),
+            ("0 0 15W * *", "2026-08-01 00:00", "2026-08-14 00:00"),
+            ("0 0 1W * *", "2026-08-01 00:00", "2026-08-03 00:00"),
+            ("0 0 ? * 5L", "2026-10-01 00:00", "2026-10-30 00:00"),
+            ("0 0 ? * FRIL", "2026-10-01 00:00", "2026-10-30 00:00"),
+            ("0 0 ? * 1#2", "2026-10-01 00:00", "2026-10-12 00:00"),
+            ("0 0 ? * MON#1,5L", "2026-10-06 00:00", "2026-10-30 00:00"),
+            ("0 0 1,L * *", "2026-06-02 00:00", "2026-06-30 00:00"),
+        ];
+        for (cron, from, expected) in cases {
+            let parsed = parse_cron_expression(cron).unwrap();
+            let next = next_cron_match(&parsed, naive(from));
+            assert_eq!(next, Some(naive(expected)), "{cron} from {from}");
+        }
+    }
+
+    #[test]
+    fn next_cron_match_rolls_over_month_and_year_ends() {
+        let cases = [
+            ("0 0 31 * *", "2026-04-01 00:00", "2026-05-31 00:00"),
+            ("0 0 30 * *", "2026-01-31 00:00", "2026-03-30 00:00"),
+            ("0 0 29 2 *", "2026-03-01 00:00", "2028-02-29 00:00"),
+            ("30 23 L 12 *", "2026-12-31 23:31", "2027-12-31 23:30"),
+            ("59 23 * * *", "2026-01-31 23:59", "2026-01-31 23:59"),
+            ("0 0 * * *", "2026-12-31 00:01", "2027-01-01 00:00"),
+            ("0 0 L-30 * *", "2026-02-01 00:00", "2026-03-01 00:00"),
+        ];
+        for (cron, from, expected) in cases {
+            let parsed = parse_cron_expression(cron).unwrap();
+            let next = next_cron_match(&parsed, naive(from));
+            assert_eq!(next, Some(naive(expected)), "{cron} from {from}");
+        }
+    }
+
+    #[test]
+    fn next_cron_occurrences_handle_dst_transitions() {
+        let berlin = resolve_plan_time_zone(Some("Europe/Berlin")).unwrap();
+        let cases: [(&str, &str, &[&str]); 5] = [
+            (
+                "30 2 * * *",
+                "2026-03-28T12:00:00+01:00",
+                &[
+                    "2026-03-29 03:30 +02:00",
+                    "2026-03-30 02:30 +02:00",
+                    "2026-03-31 02:30 +02:00",
+                ],
+            ),
+            (
+                "30 * * * *",
+                "2026-03-29T00:45:00+01:00",
+                &[
+                    "2026-03-29 01:30 +01:00",
+                    "2026-03-29 03:30 +02:00",
+                    "2026-03-29 04:30 +02:00",
+                ],
+            ),
+            (
+                "30 2 * * *",
+                "2026-10-24T12:00:00+02:00",
+                &["2026-10-25 02:30 +02:00", "2026-10-26 02:30 +01:00"],
+            ),
+            (
+                "0,30 * * * *",
+                "2026-10-25T01:45:00+02:00",
+                &[
+                    "2026-10-25 02:00 +02:00",
+                    "2026-10-25 02:30 +02:00",
+                    "2026-10-25 03:00 +01:00",
+                ],
+            ),
+            (
+                "0 0 L * *",
+                "2026-01-31T00:00:00+01:00",
+                &["2026-02-28 00:00 +01:00", "2026-03-31 00:00 +02:00"],
+            ),
+        ];
+        for (cron, after, expected) in cases {
+            let parsed = parse_cron_expression(cron).unwrap();
+            let occurrences =
+                next_cron_occurrences(&parsed, &berlin, timestamp(after), expected.len())
+                    .into_iter()
+                    .map(|value| local(&berlin, value))
+                    .collect::<Vec<_>>();
+            assert_eq!(occurrences, expected, "{cron} after {after}");
+        }
+    }
+
+    #[test]
+    fn parse_cron_expression_rejects_invalid_fields() {
+        let cases = [
+            ("0 0 * *", "expected 5 fields"),
+            ("0 0 * * * *", "expected 5 fields"),
+            ("@reboot", "@reboot is not supported"),
+            ("@fortnightly", "unknown macro"),
+            ("60 * * * *", "minute field '60': value out of range 0-59"),
+            ("0 24 * * *", "hour field '24': value out of range 0-23"),
+            (
+                "0 0 0 * *",
+                "day-of-month field '0': value out of range 1-31",
+            ),
+            ("0 0 * 13 *", "month field '13': value out of range 1-12"),
+            ("0 0 * JANX *", "month field 'JANX': unknown value 'JANX'"),
+            ("*/0 * * * *", "step must be greater than 0"),
+            ("0 0 5-1 * *", "range start 5 is after range end 1"),
+            ("0 0 1,,2 * *", "empty list entry"),
+            ("0 0 L-31 * *", "last-day offset must be between 0 and 30"),
+            ("0 0 L-x * *", "invalid last-day offset 'x'"),
+            (
+                "0 0 32W * *",
+                "nearest-weekday day must be between 1 and 31",
+            ),
+            ("0 0 0W * *", "nearest-weekday day must be between 1 and 31"),
+            (
+                "0 0 ? * 1#6",
+                "occurrence after '#' must be between 1 and 5",
+            ),
+            (
+                "0 0 ? * 1#0",
+                "occurrence after '#' must be between 1 and 5",
+            ),
+            ("0 0 ? * 8L", "value out of range 0-7"),
+            ("0 0 ? * XYZ#1", "unknown value 'XYZ'"),
+        ];
+        for (cron, expected) in cases {
+            let error = parse_cron_expression(cron).err().unwrap_or_else(|| {
+                panic!("{cron} should be rejected");
+            });
+            assert!(error.contains(expected), "{cron}: {error}");
+        }
+    }
+
+    #[test]
+    fn parse_blackout_days_handles_lists_and_wrapping_ranges() {
+        let cases = [
+            ("*", Ok([true; 7])),
+            ("mon", Ok([false, true, false, false, false, false, false])),
+            ("Mon-Fri", Ok([false, true, true, true, true, true, false])),
+            ("fri-mon", Ok([true, true, false, false, false, true, true])),
+            (
+                "sat,sun",
+                Ok([true, false, false, false, false, false, true]),
+            ),
+            (
+                "sun-sun",
+                Ok([true, false, false, false, false, false, false]),
+            ),
+            (
+                "mon,wed-thu",
+                Ok([false, true, false, true, true, false, false]),
+            ),
+            ("funday", Err("unknown day 'funday' (expected sun..sat)")),
+            ("mon-", Err("unknown day '' (expected sun..sat)")),
+            ("mon,,tue", Err("unknown day '' (expected sun..sat)")),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(
+                parse_blackout_days(raw),
+                expected.map_err(ToString::to_string),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_time_of_day_accepts_end_of_day() {
+        let cases = [
+            ("00:00", Some(0)),
+            ("7:05", Some(425)),
+            ("23:59", Some(1439)),
+            ("24:00", Some(1440)),
+            ("24:01", None),
+            ("12:60", None),
+            ("12", None),
+            ("ab:cd", None),
+            ("-1:00", None),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(parse_time_of_day(raw).ok(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn parse_blackout_window_rejects_invalid_specs() {
+        let cases = [
+            ("", "expected '<days> <HH:MM>-<HH:MM>'"),
+            ("mon 09:00", "invalid time range '09:00'"),
+            ("mon 9-17", "invalid time '9'"),
+            ("mon 09:00-09:00", "start and end time must differ"),
+            ("mon 09:00-17:00 now", "expected '<days> <HH:MM>-<HH:MM>'"),
+            ("x/y", "invalid time 'x' (expected RFC 3339)"),
+            (
+                "2026-10-17T12:00:00Z/2026-10-17T10:00:00Z",
+                "end must be after start",
+            ),
+        ];
+        for (spec, expected) in cases {
+            let error = parse_blackout_window(spec).err().unwrap_or_else(|| {
+                panic!("{spec:?} should be rejected");
+            });
+            assert!(error.contains(expected), "{spec:?}: {error}");
+        }
+    }
+
+    #[test]
+    fn blackout_window_active_handles_overnight_and_one_off_windows() {
+        let cases = [
+            ("mon-fri 09:00-17:00", "2026-10-19T09:00:00Z", true),
+            ("mon-fri 09:00-17:00", "2026-10-19T08:59:00Z", false),
+            ("mon-fri 09:00-17:00", "2026-10-19T17:00:00Z", false),
+            ("mon-fri 09:00-17:00", "2026-10-17T10:00:00Z", false),
+            ("fri 22:00-06:00", "2026-10-23T23:00:00Z", true),
+            ("fri 22:00-06:00", "2026-10-24T05:59:00Z", true),
+            ("fri 22:00-06:00", "2026-10-24T06:00:00Z", false),
+            ("fri 22:00-06:00", "2026-10-23T03:00:00Z", false),
+            ("fri 22:00-06:00", "2026-10-24T23:00:00Z", false),
+            ("fri-mon 22:00-06:00", "2026-10-20T05:00:00Z", true),
+            ("fri-mon 22:00-06:00", "2026-10-21T05:00:00Z", false),
+            ("sun 20:00-24:00", "2026-10-25T23:59:00Z", true),
+            ("sun 20:00-24:00", "2026-10-26T00:00:00Z", false),
+            ("* 00:00-24:00", "2026-10-21T12:00:00Z", true),
+            (
+                "2026-10-17T10:00:00Z/2026-10-17T12:00:00Z",
+                "2026-10-17T10:00:00Z",
+                true,
+            ),
+            (
+                "2026-10-17T10:00:00Z/2026-10-17T12:00:00Z",
+                "2026-10-17T12:00:00Z",
+                false,
+            ),
+            (
+                "2026-10-17T12:00:00+02:00/2026-10-17T13:00:00+02:00",
+                "2026-10-17T10:30:00Z",
+                true,
+            ),
+        ];
+        for (spec, at, expected) in cases {
+            let window = parse_blackout_window(spec).unwrap();
+            assert_eq!(
+                blackout_window_active(&window, &TimeZone::UTC, timestamp(at)),
+                expected,
+                "{spec} at {at}"
+            );
+        }
+
+        let berlin = resolve_plan_time_zone(Some("Europe/Berlin")).unwrap();
+        let window = parse_blackout_window("sat 23:00-01:00 stop").unwrap();
+        assert!(window.stop_running);
+        assert!(blackout_window_active(
+            &window,
+            &berlin,
+            timestamp("2026-10-24T22:30:00Z")
+        ));
+        assert!(!blackout_window_active(
+            &window,
+            &berlin,
+            timestamp("2026-10-25T00:30:00Z")
+        ));
+    }
+
+    #[test]
+    fn classify_backup_error_matches_rustic_and_rclone_errors() {
+        use BackupErrorClass::*;
+
+        let cases = [
+            (
+                StatusCode::BAD_REQUEST,
+                "paths must not be empty",
+                InvalidRequest,
+            ),
+            (
+                StatusCode::BAD_REQUEST,
+                "connection refused",
+                InvalidRequest,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "error: repository is locked by another process",
+                Locked,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "Fatal: unable to create lock in backend: repository is already locked exclusively",
+                Locked,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "dial tcp 10.0.0.5:9000: connect: connection refused",
+                Network,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "error sending request for url (https://s3.example.com/bucket/config): operation timed out",
+                Network,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "Failed to create file system: couldn't connect SFTP: dial tcp: lookup nas: no such host",
+                Network,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "HTTP status server error (503 Service Unavailable) for url (https://s3.example.com/bucket/keys/)",
+                Backend,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "backend returned status code: 500",
+                Backend,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "unexpected HTTP status=502",
+                Backend,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "googleapi: Error 429: Too Many Requests, rateLimitExceeded",
+                Backend,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "SlowDown: Please reduce your request rate.",
+                Backend,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "HTTP status client error (403 Forbidden) for url (https://s3.example.com/bucket/config)",
+                Other,
+            ),
+            (StatusCode::BAD_GATEWAY, "http error 404: not found", Other),
+            (
+                StatusCode::BAD_GATEWAY,
+                "snapshot 5032abcd not found",
+                Other,
+            ),
+            (
+                StatusCode::BAD_GATEWAY,
+                "pack 500a1b2c has status 4040",
+                Other,
+            ),
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                "rustic backup timed out after 3600s",
+                Timeout,
+            ),
+            (
+                StatusCode::FAILED_DEPENDENCY,
+                "pre-backup hook failed: connection refused",
+                Hook,
+            ),
+            (
+                StatusCode::FAILED_DEPENDENCY,
+                "post-backup hook failed: exit status 503",
+                Hook,
+            ),
+        ];
+        for (status, error, expected) in cases {
+            assert!(
+                classify_backup_error(status, error) == expected,
+                "{status} {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_the_cap() {
+        let retry = RetryPolicy {
+            initial_backoff_seconds: 30,
+            max_backoff_seconds: 600,
+            ..RetryPolicy::default()
+        };
+        let cases = [
+            (0, 30),
+            (1, 30),
+            (2, 60),
+            (3, 120),
+            (5, 480),
+            (6, 600),
+            (64, 600),
+        ];
+        for (attempt, expected) in cases {
+            assert_eq!(
+                retry_backoff(&retry, attempt),
+                Duration::from_secs(expected),
+                "attempt {attempt}"
+            );
+        }
+
+        let retry = RetryPolicy {
+            initial_backoff_seconds: u64::MAX / 2,
+            max_backoff_seconds: u64::MAX,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(retry_backoff(&retry, 10), Duration::from_secs(u64::MAX));
+    }
+
+    #[test]
+    fn parse_eta_seconds_handles_clock_and_unit_formats() {
+        let cases = [
+            ("5s", Some(5)),
+            ("1m 30s", Some(90)),
+            ("1 h 5 m", Some(3900)),
+            ("3 minutes", Some(180)),
+            ("2 days 1 hour", Some(176_400)),
+            ("1w", Some(604_800)),
+            ("2:05", Some(125)),
+            ("01:02:03", Some(3723)),
+            ("90", None),
+            ("1:xx", None),
+            ("5x", None),
+            ("soon", None),
+            ("", None),
+        ];
+        for (eta, expected) in cases {
+            assert_eq!(parse_eta_seconds(eta), expected, "{eta:?}");
+        }
+    }
+
+    #[test]
+    fn parse_progress_line_reads_counts_bytes_percent_and_eta() {
+        let cases = [
+            (
+                "[00:00:05] backing up...   ████████░░░░ 1.50 GiB/3.00 GiB 300 MiB/s (ETA 5s)",
+                Some((
+                    "backing up...",
+                    None,
+                    None,
+                    Some(1_610_612_736),
+                    Some(3_221_225_472),
+                    None,
+                    Some(5),
+                )),
+            ),
+            (
+                "[00:00:01] reading index...  ████████████ 12/40",
+                Some((
+                    "reading index...",
+                    Some(12),
+                    Some(40),
+                    None,
+                    None,
+                    None,
+                    None,
+                )),
+            ),
+            (
+                "\u{1b}[32m[00:01:10]\u{1b}[0m scanning 1,024/2,048 files 50% ETA: 1:30",
+                Some((
+                    "scanning",
+                    Some(1024),
+                    Some(2048),
+                    None,
+                    None,
+                    Some(50.0),
+                    Some(90),
+                )),
+            ),
+            (
+                "[00:00:03] getting snapshots...  42%",
+                Some((
+                    "getting snapshots...",
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(42.0),
+                    None,
+                )),
+            ),
+            (
+                "backing up 12 / 40 files (ETA 2m 5s)",
+                Some((
+                    "backing up",
+                    Some(12),
+                    Some(40),
+                    None,
+                    None,
+                    None,
+                    Some(125),
+                )),
+            ),
+            (
+                "backing up 512 B / 2 KiB",
+                Some(("backing up", None, None, Some(512), Some(2048), None, None)),
+            ),
+            (
+                "restoring 5.0 MiB/10.0 MiB (ETA 1h 2m)",
+                Some((
+                    "restoring",
+                    None,
+                    None,
+                    Some(5_242_880),
+                    Some(10_485_760),
+                    None,
+                    Some(3720),
+                )),
+            ),
+            (
+                "checking /srv/ETAs/old 10/20",
+                Some(("checking", Some(10), Some(20), None, None, None, None)),
+            ),
+            ("error: cannot read /data/500/600: METADATA missing", None),
+            ("WARN: 3/4 retries left", None),
+            ("reading a/b/c", None),
+            ("[00:00:02] processing 1.5/3", None),
+            ("backup finished", None),
+            ("", None),
+        ];
+        for (line, expected) in cases {
+            let progress = parse_progress_line(line);
+            let parsed = progress.as_ref().map(|progress| {
+                (
+                    progress.message.as_deref().unwrap_or_default(),
+                    progress.files_done,
+                    progress.files_total,
+                    progress.bytes_done,
+                    progress.bytes_total,
+                    progress.percent_done,
+                    progress.eta_seconds,
+                )
+            });
+            assert_eq!(parsed, expected, "{line:?}");
+        }
+    }
+
+    fn repository_request<T: serde::de::DeserializeOwned>(fields: Value) -> T {
+        let mut request = serde_json::json!({ "repository": "/srv/repo" });
+        if let (Value::Object(request), Value::Object(fields)) = (&mut request, fields) {
+            request.extend(fields);
+        }
+        serde_json::from_value(request).unwrap()
+    }
+
+    #[test]
+    fn parse_size_limit_accepts_decimal_and_binary_units() {
+        let cases = [
+            ("1048576", Some(1_048_576)),
+            ("500M", Some(500_000_000)),
+            ("500 mb", Some(500_000_000)),
+            ("1.5k", Some(1500)),
+            ("2GiB", Some(2_147_483_648)),
+            ("2 gi", Some(2_147_483_648)),
+            ("1T", Some(1_000_000_000_000)),
+            ("0.5", None),
+            ("0", None),
+            ("", None),
+            ("M", None),
+            ("huge", None),
+            ("5 XB", None),
+            ("-5M", None),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(parse_size_limit(raw), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn backup_filter_args_maps_and_validates_options() {
+        let glob_file = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let cases = [
+            (serde_json::json!({}), Ok(vec![])),
+            (
+                serde_json::json!({ "globs": ["!*.tmp"], "iglobs": ["*.LOG"] }),
+                Ok(vec!["--glob", "!*.tmp", "--iglob", "*.LOG"]),
+            ),
+            (
+                serde_json::json!({ "globFiles": [format!(" {glob_file} ")] }),
+                Ok(vec!["--glob-file", glob_file]),
+            ),
+            (
+                serde_json::json!({ "excludeIfPresent": [".nobackup", " CACHEDIR.TAG "] }),
+                Ok(vec![
+                    "--exclude-if-present",
+                    ".nobackup",
+                    "--exclude-if-present",
+                    "CACHEDIR.TAG",
+                ]),
+            ),
+            (
+                serde_json::json!({ "excludeLargerThan": " 500 M " }),
+                Ok(vec!["--exclude-larger-than", "500M"]),
+            ),
+            (
+                serde_json::json!({
+                    "gitIgnore": true,
+                    "noRequireGit": true,
+                    "oneFileSystem": true,
+                }),
+                Ok(vec![
+                    "--git-ignore",
+                    "--no-require-git",
+                    "--one-file-system",
+                ]),
+            ),
+            (
+                serde_json::json!({ "globs": [" "] }),
+                Err("globs must not contain empty patterns"),
+            ),
+            (
+                serde_json::json!({ "iglobs": ["a\nb"] }),
+                Err("iglobs pattern 'a\\nb' must not contain control characters"),
+            ),
+            (
+                serde_json::json!({ "iglobFiles": ["/nonexistent/glare.globs"] }),
+                Err("iglobFiles entry '/nonexistent/glare.globs' is not a file on this worker"),
+            ),
+            (
+                serde_json::json!({ "globFiles": [env!("CARGO_MANIFEST_DIR")] }),
+                Err(concat!(
+                    "globFiles entry '",
+                    env!("CARGO_MANIFEST_DIR"),
+                    "' is not a file on this worker"
+                )),
+            ),
+            (
+                serde_json::json!({ "excludeIfPresent": ["cache/.nobackup"] }),
+                Err(
+                    "excludeIfPresent entry 'cache/.nobackup' must be a file name without directories",
+                ),
+            ),
+            (
+                serde_json::json!({ "excludeIfPresent": [""] }),
+                Err("excludeIfPresent entry '' must be a file name without directories"),
+            ),
+            (
+                serde_json::json!({ "excludeLargerThan": "huge" }),
+                Err("excludeLargerThan 'huge' must be a size such as 500M, 2GiB or 1048576"),
+            ),
+            (
+                serde_json::json!({ "noRequireGit": true }),
+                Err("noRequireGit requires gitIgnore"),
+            ),
+        ];
+        for (fields, expected) in cases {
+            let description = fields.to_string();
+            let expected = expected
+                .map(|args| {
+                    args.into_iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(ToString::to_string);
+            assert_eq!(
+                backup_filter_args(&repository_request(fields)),
+                expected,
+                "{description}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_prune_counts_reads_packs_blobs_and_sizes() {
+        let cases = [
+            (
+                "5 packs, 120 blobs, 12.5 MiB",
+                (Some(5), Some(120), Some(13_107_200)),
+            ),
+            (" 40 blobs, 4.0 MiB", (None, Some(40), Some(4_194_304))),
+            ("0 B", (None, None, Some(0))),
+            ("1.5 kB", (None, None, Some(1500))),
+            (
+                "10.0 MiB (0.98% of remaining size)",
+                (None, None, Some(10_485_760)),
+            ),
+            ("2 packs, 30 blobs, 1536", (Some(2), Some(30), None)),
+            ("2 packs, 30 blobs, 1.5 XB", (Some(2), Some(30), None)),
+            ("many packs", (None, None, None)),
+            ("", (None, None, None)),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(parse_prune_counts(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn parse_prune_stats_reads_rustic_summary() {
+        let output = "\
+[INFO] to repack: 5 packs, 120 blobs, 12.5 MiB
+[INFO] this removes: 40 blobs, 4.0 MiB
+[2026-10-17T10:00:00Z INFO] to delete: 2 packs, 30 blobs, 1.5 MiB
+\u{1b}[1m[INFO]\u{1b}[0m total prune: 70 blobs, 5.5 MiB
+remaining: 1000 blobs, 1.0 GiB
+unused size after prune: 10.0 MiB (0.98% of remaining size)
+unrelated line: 7 blobs, 1 KiB
+";
+        assert_eq!(
+            serde_json::to_value(parse_prune_stats(output)).unwrap(),
+            serde_json::json!({
+                "packsToRepack": 5,
+                "blobsToRepack": 120,
+                "bytesToRepack": 13_107_200,
+                "blobsRemovedByRepack": 40,
+                "bytesRemovedByRepack": 4_194_304,
+                "packsToDelete": 2,
+                "blobsToDelete": 30,
+                "bytesToDelete": 1_572_864,
+                "blobsPruned": 70,
+                "bytesFreed": 5_767_168,
+                "blobsRemaining": 1000,
+                "bytesRemaining": 1_073_741_824,
+                "unusedBytesAfterPrune": 10_485_760,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_prune_stats_derives_freed_bytes_and_skips_missing_units() {
+        let cases = [
+            (
+                "to delete: 2 packs, 30 blobs, 1.5 MiB\nthis removes: 40 blobs, 4.0 MiB\n",
+                Some(5_767_168),
+                Some(1_572_864),
+            ),
+            ("to delete: 2 packs, 30 blobs, 1536\n", None, None),
+            ("nothing to do\n", None, None),
+        ];
+        for (output, bytes_freed, bytes_to_delete) in cases {
+            let stats = parse_prune_stats(output);
+            assert_eq!(stats.bytes_freed, bytes_freed, "{output:?}");
+            assert_eq!(stats.bytes_to_delete, bytes_to_delete, "{output:?}");
+        }
+    }
+
+    #[test]
+    fn forget_selection_args_maps_and_validates_options() {
+        let cases = [
+            (
+                serde_json::json!({ "keepDaily": 7, "keepHourly": 24, "keepTags": ["important"] }),
+                Ok(vec![
+                    "--keep-hourly",
+                    "24",
+                    "--keep-daily",
+                    "7",
+                    "--keep-tag",
+                    "important",
+                ]),
+            ),
+            (
+                serde_json::json!({ "keepNone": true }),
+                Ok(vec!["--keep-none"]),
+            ),
+            (
+                serde_json::json!({
+                    "keepLast": 3,
+                    "groupBy": " host,tags ",
+                    "filterHosts": ["web-1"],
+                    "filterTags": [" nightly,db "],
+                    "filterPaths": ["/srv"],
+                }),
+                Ok(vec![
+                    "--keep-last",
+                    "3",
+                    "--group-by",
+                    "host,tags",
+                    "--filter-host",
+                    "web-1",
+                    "--filter-tags",
+                    "nightly,db",
+                    "--filter-paths",
+                    "/srv",
+                ]),
+            ),
+            (
+                serde_json::json!({}),
+                Err(
+                    "forget requires at least one keep rule; set keepNone to remove every matching snapshot",
+                ),
+            ),
+            (
+                serde_json::json!({ "keepNone": true, "keepLast": 1 }),
+                Err("keepNone cannot be combined with other keep rules"),
+            ),
+            (
+                serde_json::json!({ "keepTags": ["important", " "] }),
+                Err("keepTags entries must not be empty"),
+            ),
+            (
+                serde_json::json!({ "keepLast": 1, "groupBy": "host,host" }),
+                Err("groupBy lists 'host' more than once"),
+            ),
+            (
+                serde_json::json!({ "keepLast": 1, "groupBy": "hostname" }),
+                Err(
+                    "invalid groupBy 'hostname'; use a comma-separated list of host, label, paths, tags",
+                ),
+            ),
+            (
+                serde_json::json!({ "keepLast": 1, "filterHosts": [""] }),
+                Err("filterHosts entries must not be empty"),
+            ),
+        ];
+        for (fields, expected) in cases {
+            let description = fields.to_string();
+            let request: RusticForgetRequest = repository_request(fields);
+            let expected = expected
+                .map(|args| {
+                    args.into_iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(ToString::to_string);
+            assert_eq!(forget_selection_args(&request), expected, "{description}");
+        }
+    }
+
+    #[test]
+    fn parse_forget_groups_splits_kept_and_removed_snapshots() {
+        let output = serde_json::json!([
+            {
+                "group": { "hostname": "web-1", "label": "", "paths": ["/srv"] },
+                "snapshots": [
+                    {
+                        "snapshot": { "id": "aaa", "time": "2026-10-17T00:00:00Z" },
+                        "keep": true,
+                        "reasons": ["daily", "weekly"],
+                    },
+                    { "snapshot": { "id": "bbb" }, "keep": false, "reasons": [] },
+                    { "snapshot": { "id": "ccc" } },
+                    { "snapshot": {}, "keep": false },
+                ],
+            },
+            { "group": null },
+        ]);
+        assert_eq!(
+            serde_json::to_value(parse_forget_groups(Some(&output))).unwrap(),
+            serde_json::json!([
+                {
+                    "group": { "hostname": "web-1", "label": "", "paths": ["/srv"] },
+                    "kept": [
+                        {
+                            "id": "aaa",
+                            "time": "2026-10-17T00:00:00Z",
+                            "reasons": ["daily", "weekly"],
+                        },
+                        { "id": "ccc", "time": null, "reasons": [] },
+                    ],
+                    "removed": [{ "id": "bbb", "time": null, "reasons": [] }],
+                },
+                { "group": null, "kept": [], "removed": [] },
+            ])
+        );
+        assert!(parse_forget_groups(None).is_empty());
+        assert!(parse_forget_groups(Some(&serde_json::json!({ "groups": [] }))).is_empty());
+    }
+}
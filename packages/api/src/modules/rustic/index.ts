@@ -19,6 +19,26 @@ import { writeAuditLog } from "../../shared/audit-log";
 
 const WORKER_ONLINE_THRESHOLD_MS = 45_000;
 const BACKEND_VALUES = ["local", "s3", "b2", "rest", "webdav", "sftp", "rclone", "other"] as const;
+const OVERLAP_POLICY_VALUES = ["skip", "queue_one", "allow"] as const;
+const JITTER_MODE_VALUES = ["deterministic", "random"] as const;
+const PLAN_OPERATION_VALUES = [
+  "backup",
+  "check",
+  "check_read_data_subset",
+  "forget",
+  "prune",
+  "repair_index",
+] as const;
+const READ_DATA_SUBSET_PATTERN = "^(\\d+/\\d+|\\d+(\\.\\d+)?%|\\d+(\\.\\d+)?\\s*[kKmMgGtT]?i?[bB]?)$";
+const RETRY_ERROR_CLASS_VALUES = [
+  "network",
+  "backend",
+  "locked",
+  "invalid_request",
+  "timeout",
+  "hook",
+  "other",
+] as const;
 
 const workerIdType = type("string.uuid");
 const repositoryIdType = type("string.uuid");
@@ -204,6 +224,15 @@ const updateRepositoryBodySchema = t.Object({
   s3: t.Optional(s3ConfigSchema),
 });
 
+const retryPolicySchema = t.Object({
+  maxAttempts: t.Optional(t.Number({ minimum: 1, maximum: 10, multipleOf: 1 })),
+  initialBackoffSeconds: t.Optional(t.Number({ minimum: 0, maximum: 86_400, multipleOf: 1 })),
+  maxBackoffSeconds: t.Optional(t.Number({ minimum: 0, maximum: 86_400, multipleOf: 1 })),
+  retryOn: t.Optional(
+    t.Array(t.Union(RETRY_ERROR_CLASS_VALUES.map((value) => t.Literal(value))), { maxItems: 7 }),
+  ),
+});
+
 const backupPlanSchema = t.Object({
   id: t.String({ format: "uuid" }),
   name: t.String(),
@@ -226,6 +255,15 @@ const backupPlanSchema = t.Object({
   keepMonthly: t.Union([t.Number(), t.Null()]),
   keepYearly: t.Union([t.Number(), t.Null()]),
   keepWithin: t.Union([t.String(), t.Null()]),
+  timeZone: t.Union([t.String(), t.Null()]),
+  overlapPolicy: t.String(),
+  jitterSeconds: t.Number(),
+  jitterMode: t.String(),
+  blackoutWindows: t.Array(t.String()),
+  retry: t.Union([retryPolicySchema, t.Null()]),
+  timeoutSeconds: t.Union([t.Number(), t.Null()]),
+  operation: t.String(),
+  readDataSubset: t.Union([t.String(), t.Null()]),
   repository: t.Object({
     id: t.String({ format: "uuid" }),
     name: t.String(),
@@ -316,6 +354,19 @@ const createBackupPlanBodySchema = t.Object({
   keepMonthly: t.Optional(t.Union([t.Number({ minimum: 0 }), t.Null()])),
   keepYearly: t.Optional(t.Union([t.Number({ minimum: 0 }), t.Null()])),
   keepWithin: t.Optional(t.Union([t.String({ maxLength: 64 }), t.Null()])),
+  timeZone: t.Optional(t.Union([t.String({ maxLength: 64 }), t.Null()])),
+  overlapPolicy: t.Optional(t.Union(OVERLAP_POLICY_VALUES.map((value) => t.Literal(value)))),
+  jitterSeconds: t.Optional(t.Number({ minimum: 0, maximum: 3600, multipleOf: 1 })),
+  jitterMode: t.Optional(t.Union(JITTER_MODE_VALUES.map((value) => t.Literal(value)))),
+  blackoutWindows: t.Optional(
+    t.Array(t.String({ minLength: 1, maxLength: 120 }), { maxItems: 32 }),
+  ),
+  retry: t.Optional(t.Union([retryPolicySchema, t.Null()])),
+  timeoutSeconds: t.Optional(t.Union([t.Number({ minimum: 0, multipleOf: 1 }), t.Null()])),
+  operation: t.Optional(t.Union(PLAN_OPERATION_VALUES.map((value) => t.Literal(value)))),
+  readDataSubset: t.Optional(
+    t.Union([t.String({ maxLength: 32, pattern: READ_DATA_SUBSET_PATTERN }), t.Null()]),
+  ),
 });
 
 const updateBackupPlanBodySchema = t.Object({
@@ -340,6 +391,19 @@ const updateBackupPlanBodySchema = t.Object({
   keepMonthly: t.Optional(t.Union([t.Number({ minimum: 0 }), t.Null()])),
   keepYearly: t.Optional(t.Union([t.Number({ minimum: 0 }), t.Null()])),
   keepWithin: t.Optional(t.Union([t.String({ maxLength: 64 }), t.Null()])),
+  timeZone: t.Optional(t.Union([t.String({ maxLength: 64 }), t.Null()])),
+  overlapPolicy: t.Optional(t.Union(OVERLAP_POLICY_VALUES.map((value) => t.Literal(value)))),
+  jitterSeconds: t.Optional(t.Number({ minimum: 0, maximum: 3600, multipleOf: 1 })),
+  jitterMode: t.Optional(t.Union(JITTER_MODE_VALUES.map((value) => t.Literal(value)))),
+  blackoutWindows: t.Optional(
+    t.Array(t.String({ minLength: 1, maxLength: 120 }), { maxItems: 32 }),
+  ),
+  retry: t.Optional(t.Union([retryPolicySchema, t.Null()])),
+  timeoutSeconds: t.Optional(t.Union([t.Number({ minimum: 0, multipleOf: 1 }), t.Null()])),
+  operation: t.Optional(t.Union(PLAN_OPERATION_VALUES.map((value) => t.Literal(value)))),
+  readDataSubset: t.Optional(
+    t.Union([t.String({ maxLength: 32, pattern: READ_DATA_SUBSET_PATTERN }), t.Null()]),
+  ),
 });
 
 const repositorySnapshotFilesBodySchema = t.Object({
@@ -451,6 +515,15 @@ type BackupPlanRecord = {
   keepMonthly: number | null;
   keepYearly: number | null;
   keepWithin: string | null;
+  timeZone: string | null;
+  overlapPolicy: string;
+  jitterSeconds: number;
+  jitterMode: string;
+  blackoutWindowsJson: string | null;
+  retryJson: string | null;
+  timeoutSeconds: number | null;
+  operation: string;
+  readDataSubset: string | null;
   createdAt: Date;
   updatedAt: Date;
 };
@@ -505,6 +578,7 @@ type BackupEventRecord = {
 
 type RusticWorkerStats = typeof rusticWorkerStatsSchema.static;
 type RusticRepository = typeof rusticRepositorySchema.static;
+type RetryPolicy = typeof retryPolicySchema.static;
 type RusticBackupPlan = typeof backupPlanSchema.static;
 type RusticBackupPlanRun = typeof backupPlanRunSchema.static;
 type S3Config = typeof s3ConfigSchema.static;
@@ -757,6 +831,22 @@ function filterSensitiveOptions(options: Record<string, string>): Record<string,
   return next;
 }
 
+function parseRetryPolicyJson(value: string | null): RetryPolicy | null {
+  if (!value) {
+    return null;
+  }
+
+  try {
+    const parsed = JSON.parse(value) as unknown;
+    if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+      return null;
+    }
+    return parsed as RetryPolicy;
+  } catch {
+    return null;
+  }
+}
+
 function parseStringArrayJson(value: string | null): string[] {
   if (!value) return [];
   try {
@@ -1076,98 +1166,426 @@ function extractSnapshotRefFromDetailsJson(detailsJson: string | null) {
   }
 }
 
-function parseCronField(raw: string, min: number, max: number): Set<number> | null {
-  const values = new Set<number>();
-  const chunks = raw.split(",");
+type CronFieldSpec = {
+  label: string;
+  min: number;
+  max: number;
+  names: readonly string[];
+  firstNamedValue: number;
+};
+
+const CRON_MINUTE_FIELD: CronFieldSpec = {
+  label: "minute",
+  min: 0,
+  max: 59,
+  names: [],
+  firstNamedValue: 0,
+};
+const CRON_HOUR_FIELD: CronFieldSpec = {
+  label: "hour",
+  min: 0,
+  max: 23,
+  names: [],
+  firstNamedValue: 0,
+};
+const CRON_DAY_OF_MONTH_FIELD: CronFieldSpec = {
+  label: "day-of-month",
+  min: 1,
+  max: 31,
+  names: [],
+  firstNamedValue: 0,
+};
+const CRON_MONTH_FIELD: CronFieldSpec = {
+  label: "month",
+  min: 1,
+  max: 12,
+  names: ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"],
+  firstNamedValue: 1,
+};
+const CRON_DAY_OF_WEEK_FIELD: CronFieldSpec = {
+  label: "day-of-week",
+  min: 0,
+  max: 7,
+  names: ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+  firstNamedValue: 0,
+};
+
+const CRON_MACROS: Record<string, string> = {
+  "@yearly": "0 0 1 1 *",
+  "@annually": "0 0 1 1 *",
+  "@monthly": "0 0 1 * *",
+  "@weekly": "0 0 * * 0",
+  "@daily": "0 0 * * *",
+  "@midnight": "0 0 * * *",
+  "@hourly": "0 * * * *",
+};
+
+type DayOfMonthRule =
+  | { kind: "lastDay"; offset: number }
+  | { kind: "lastWeekday" }
+  | { kind: "nearestWeekday"; day: number };
+
+type DayOfWeekRule =
+  | { kind: "lastInMonth"; weekday: number }
+  | { kind: "nthInMonth"; weekday: number; nth: number };
+
+type ParsedCron = {
+  minute: Set<number>;
+  hour: Set<number>;
+  dayOfMonth: Set<number>;
+  dayOfMonthRules: DayOfMonthRule[];
+  month: Set<number>;
+  dayOfWeek: Set<number>;
+  dayOfWeekRules: DayOfWeekRule[];
+  isDayOfMonthWildcard: boolean;
+  isDayOfWeekWildcard: boolean;
+};
 
-  for (const chunkRaw of chunks) {
+function parseCronValue(raw: string, field: CronFieldSpec) {
+  if (/^\d+$/.test(raw)) return Number(raw);
+  const index = field.names.indexOf(raw.toUpperCase());
+  return index === -1 ? null : index + field.firstNamedValue;
+}
+
+function parseCronChunk(chunk: string, field: CronFieldSpec, values: Set<number>) {
+  const separator = chunk.indexOf("/");
+  const baseRaw = separator === -1 ? chunk : chunk.slice(0, separator);
+  const stepRaw = separator === -1 ? null : chunk.slice(separator + 1);
+  const step = stepRaw === null ? 1 : /^\d+$/.test(stepRaw) ? Number(stepRaw) : null;
+  if (step === null) return `invalid step '${stepRaw}'`;
+  if (step === 0) return "step must be greater than 0";
+
+  const isDayOfWeek = field === CRON_DAY_OF_WEEK_FIELD;
+  let rangeMin: number;
+  let rangeMax: number;
+  if (baseRaw === "*" || baseRaw === "?") {
+    rangeMin = field.min;
+    rangeMax = isDayOfWeek ? 6 : field.max;
+  } else if (baseRaw.includes("-")) {
+    const dash = baseRaw.indexOf("-");
+    const startRaw = baseRaw.slice(0, dash);
+    const endRaw = baseRaw.slice(dash + 1);
+    const start = parseCronValue(startRaw, field);
+    if (start === null) return `unknown value '${startRaw}'`;
+    let end = parseCronValue(endRaw, field);
+    if (end === null) return `unknown value '${endRaw}'`;
+    if (isDayOfWeek && end === 0 && start > 0) end = 7;
+    rangeMin = start;
+    rangeMax = end;
+  } else {
+    const single = parseCronValue(baseRaw, field);
+    if (single === null) return `unknown value '${baseRaw}'`;
+    rangeMin = single;
+    rangeMax = stepRaw === null ? single : field.max;
+  }
+
+  if (rangeMin < field.min || rangeMax > field.max) {
+    return `value out of range ${field.min}-${field.max}`;
+  }
+  if (rangeMin > rangeMax) return `range start ${rangeMin} is after range end ${rangeMax}`;
+
+  for (let value = rangeMin; value <= rangeMax; value += step) {
+    values.add(isDayOfWeek && value === 7 ? 0 : value);
+  }
+  return null;
+}
+
+function parseDayOfMonthRule(chunk: string): DayOfMonthRule | string | null {
+  const upper = chunk.toUpperCase();
+  if (upper === "L") return { kind: "lastDay", offset: 0 };
+  if (upper === "LW") return { kind: "lastWeekday" };
+  if (upper.startsWith("L-")) {
+    const offsetRaw = chunk.slice(2);
+    if (!/^\d+$/.test(offsetRaw)) return `invalid last-day offset '${offsetRaw}'`;
+    const offset = Number(offsetRaw);
+    if (offset > 30) return "last-day offset must be between 0 and 30";
+    return { kind: "lastDay", offset };
+  }
+  if (upper.endsWith("W")) {
+    const dayRaw = chunk.slice(0, -1);
+    if (!/^\d+$/.test(dayRaw)) return `invalid nearest-weekday day '${dayRaw}'`;
+    const day = Number(dayRaw);
+    if (day < 1 || day > 31) return "nearest-weekday day must be between 1 and 31";
+    return { kind: "nearestWeekday", day };
+  }
+  return null;
+}
+
+function parseDayOfWeekRule(chunk: string): DayOfWeekRule | string | null {
+  const parseWeekday = (raw: string) => {
+    const value = parseCronValue(raw, CRON_DAY_OF_WEEK_FIELD);
+    if (value === null) return `unknown value '${raw}'`;
+    if (value > 7) return "value out of range 0-7";
+    return value % 7;
+  };
+
+  const hash = chunk.indexOf("#");
+  if (hash !== -1) {
+    const weekday = parseWeekday(chunk.slice(0, hash));
+    if (typeof weekday === "string") return weekday;
+    const nthRaw = chunk.slice(hash + 1);
+    if (!/^\d+$/.test(nthRaw)) return `invalid occurrence '${nthRaw}'`;
+    const nth = Number(nthRaw);
+    if (nth < 1 || nth > 5) return "occurrence after '#' must be between 1 and 5";
+    return { kind: "nthInMonth", weekday, nth };
+  }
+  if (chunk.length > 1 && chunk.toUpperCase().endsWith("L")) {
+    const weekday = parseWeekday(chunk.slice(0, -1));
+    if (typeof weekday === "string") return weekday;
+    return { kind: "lastInMonth", weekday };
+  }
+  return null;
+}
+
+function parseCronField<Rule>(
+  raw: string,
+  field: CronFieldSpec,
+  parseRule?: (chunk: string) => Rule | string | null,
+) {
+  const values = new Set<number>();
+  const rules: Rule[] = [];
+  for (const chunkRaw of raw.split(",")) {
     const chunk = chunkRaw.trim();
-    if (!chunk) return null;
-
-    const [baseMaybe, stepRaw] = chunk.split("/");
-    const baseRaw = baseMaybe ?? "*";
-    const step = stepRaw === undefined ? 1 : Number(stepRaw);
-    if (!Number.isInteger(step) || step <= 0) return null;
-
-    let rangeMin = min;
-    let rangeMax = max;
-    if (baseRaw !== "*") {
-      if (baseRaw.includes("-")) {
-        const [startRaw, endRaw] = baseRaw.split("-");
-        const start = Number(startRaw);
-        const end = Number(endRaw);
-        if (!Number.isInteger(start) || !Number.isInteger(end)) return null;
-        rangeMin = start;
-        rangeMax = end;
+    let error: string | null = "empty list entry";
+    if (chunk) {
+      const rule = parseRule ? parseRule(chunk) : null;
+      if (typeof rule === "string") {
+        error = rule;
+      } else if (rule) {
+        rules.push(rule);
+        error = null;
       } else {
-        const single = Number(baseRaw);
-        if (!Number.isInteger(single)) return null;
-        rangeMin = single;
-        rangeMax = single;
+        error = parseCronChunk(chunk, field, values);
       }
     }
-
-    if (rangeMin < min || rangeMax > max || rangeMin > rangeMax) return null;
-    for (let value = rangeMin; value <= rangeMax; value += step) {
-      values.add(value);
-    }
+    if (error) return { ok: false as const, error: `${field.label} field '${raw}': ${error}` };
   }
-
-  return values;
+  return { ok: true as const, values, rules };
 }
 
-function parseCronExpression(cron: string) {
-  const parts = cron.trim().split(/\s+/);
-  if (parts.length !== 5) return null;
+function parseCronExpression(
+  cron: string,
+): { ok: true; cron: ParsedCron } | { ok: false; error: string } {
+  const trimmed = cron.trim();
+  const macro = trimmed.toLowerCase();
+  if (macro === "@reboot") {
+    return { ok: false, error: "@reboot is not supported for backup plans" };
+  }
+  const expanded = trimmed.startsWith("@") ? CRON_MACROS[macro] : trimmed;
+  if (!expanded) {
+    return {
+      ok: false,
+      error: `unknown macro '${trimmed}' (supported: ${Object.keys(CRON_MACROS).join(", ")})`,
+    };
+  }
+
+  const parts = expanded.split(/\s+/);
+  if (parts.length !== 5) {
+    return {
+      ok: false,
+      error: `expected 5 fields (minute hour day-of-month month day-of-week) or a macro such as @daily, got ${parts.length}`,
+    };
+  }
   const minuteField = parts[0] ?? "";
   const hourField = parts[1] ?? "";
   const dayOfMonthField = parts[2] ?? "";
   const monthField = parts[3] ?? "";
   const dayOfWeekField = parts[4] ?? "";
 
-  const minute = parseCronField(minuteField, 0, 59);
-  const hour = parseCronField(hourField, 0, 23);
-  const dayOfMonth = parseCronField(dayOfMonthField, 1, 31);
-  const month = parseCronField(monthField, 1, 12);
-  const dayOfWeek = parseCronField(dayOfWeekField, 0, 6);
-  if (!minute || !hour || !dayOfMonth || !month || !dayOfWeek) return null;
+  const minute = parseCronField(minuteField, CRON_MINUTE_FIELD);
+  if (!minute.ok) return minute;
+  const hour = parseCronField(hourField, CRON_HOUR_FIELD);
+  if (!hour.ok) return hour;
+  const month = parseCronField(monthField, CRON_MONTH_FIELD);
+  if (!month.ok) return month;
+  const dayOfMonth = parseCronField(dayOfMonthField, CRON_DAY_OF_MONTH_FIELD, parseDayOfMonthRule);
+  if (!dayOfMonth.ok) return dayOfMonth;
+  const dayOfWeek = parseCronField(dayOfWeekField, CRON_DAY_OF_WEEK_FIELD, parseDayOfWeekRule);
+  if (!dayOfWeek.ok) return dayOfWeek;
 
-  const isDayOfMonthWildcard = dayOfMonthField === "*";
-  const isDayOfWeekWildcard = dayOfWeekField === "*";
-
-  return { minute, hour, dayOfMonth, month, dayOfWeek, isDayOfMonthWildcard, isDayOfWeekWildcard };
+  return {
+    ok: true,
+    cron: {
+      minute: minute.values,
+      hour: hour.values,
+      dayOfMonth: dayOfMonth.values,
+      dayOfMonthRules: dayOfMonth.rules,
+      month: month.values,
+      dayOfWeek: dayOfWeek.values,
+      dayOfWeekRules: dayOfWeek.rules,
+      isDayOfMonthWildcard: dayOfMonthField === "*" || dayOfMonthField === "?",
+      isDayOfWeekWildcard: dayOfWeekField === "*" || dayOfWeekField === "?",
+    },
+  };
 }
 
-function cronMatchesDate(date: Date, parsed: NonNullable<ReturnType<typeof parseCronExpression>>) {
-  const minute = date.getMinutes();
-  const hour = date.getHours();
-  const dayOfMonth = date.getDate();
-  const month = date.getMonth() + 1;
-  const dayOfWeek = date.getDay();
+function daysInMonth(year: number, month: number) {
+  return new Date(Date.UTC(year, month, 0)).getUTCDate();
+}
 
-  if (!parsed.minute.has(minute) || !parsed.hour.has(hour) || !parsed.month.has(month))
-    return false;
+function nearestWeekday(year: number, month: number, day: number) {
+  const lastDay = daysInMonth(year, month);
+  if (day > lastDay) return null;
+  const weekday = new Date(Date.UTC(year, month - 1, day)).getUTCDay();
+  if (weekday === 6) return day === 1 ? 3 : day - 1;
+  if (weekday === 0) return day === lastDay ? day - 2 : day + 1;
+  return day;
+}
 
-  const domMatch = parsed.dayOfMonth.has(dayOfMonth);
-  const dowMatch = parsed.dayOfWeek.has(dayOfWeek);
+function cronMatchesDay(parsed: ParsedCron, year: number, month: number, day: number) {
+  if (!parsed.month.has(month)) return false;
+
+  const lastDay = daysInMonth(year, month);
+  const weekday = new Date(Date.UTC(year, month - 1, day)).getUTCDay();
+  const domMatch =
+    parsed.dayOfMonth.has(day) ||
+    parsed.dayOfMonthRules.some((rule) => {
+      if (rule.kind === "lastDay") return lastDay - rule.offset === day;
+      if (rule.kind === "lastWeekday") return nearestWeekday(year, month, lastDay) === day;
+      return nearestWeekday(year, month, rule.day) === day;
+    });
+  const dowMatch =
+    parsed.dayOfWeek.has(weekday) ||
+    parsed.dayOfWeekRules.some((rule) => {
+      if (rule.weekday !== weekday) return false;
+      if (rule.kind === "lastInMonth") return day + 7 > lastDay;
+      return Math.floor((day - 1) / 7) + 1 === rule.nth;
+    });
   if (parsed.isDayOfMonthWildcard && parsed.isDayOfWeekWildcard) return true;
   if (parsed.isDayOfMonthWildcard) return dowMatch;
   if (parsed.isDayOfWeekWildcard) return domMatch;
   return domMatch || dowMatch;
 }
 
-function computeNextRun(cron: string, from: Date) {
+const CRON_SEARCH_DAYS = 50 * 366;
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+function zonedMinuteFormatter(timeZone: string | null) {
+  return new Intl.DateTimeFormat("en-US", {
+    timeZone: timeZone ?? undefined,
+    hourCycle: "h23",
+    year: "numeric",
+    month: "numeric",
+    day: "numeric",
+    hour: "numeric",
+    minute: "numeric",
+  });
+}
+
+function zonedWallTime(formatter: Intl.DateTimeFormat, instant: number) {
+  const parts: Record<string, number> = {};
+  for (const part of formatter.formatToParts(instant)) {
+    parts[part.type] = Number(part.value);
+  }
+  return Date.UTC(
+    parts.year ?? 1970,
+    (parts.month ?? 1) - 1,
+    parts.day ?? 1,
+    parts.hour ?? 0,
+    parts.minute ?? 0,
+  );
+}
+
+function instantForWallTime(formatter: Intl.DateTimeFormat, wallTime: number) {
+  const offsetBefore = zonedWallTime(formatter, wallTime - DAY_MS) - (wallTime - DAY_MS);
+  const offsetAfter = zonedWallTime(formatter, wallTime + DAY_MS) - (wallTime + DAY_MS);
+  const earlier = wallTime - offsetBefore;
+  if (zonedWallTime(formatter, earlier) === wallTime) return earlier;
+  const later = wallTime - offsetAfter;
+  if (zonedWallTime(formatter, later) === wallTime) return later;
+  return earlier;
+}
+
+function computeNextRun(cron: string, from: Date, timeZone: string | null) {
   const parsed = parseCronExpression(cron);
-  if (!parsed) return null;
-
-  const cursor = new Date(from);
-  cursor.setSeconds(0, 0);
-  cursor.setMinutes(cursor.getMinutes() + 1);
-  for (let i = 0; i < 60 * 24 * 366; i += 1) {
-    if (cronMatchesDate(cursor, parsed)) {
-      return new Date(cursor);
+  if (!parsed.ok) return null;
+
+  const formatter = zonedMinuteFormatter(timeZone);
+  const after = from.getTime();
+  const start = zonedWallTime(formatter, after) + 60_000;
+  const hours = [...parsed.cron.hour].sort((a, b) => a - b);
+  const minutes = [...parsed.cron.minute].sort((a, b) => a - b);
+  const day = new Date(start - (start % DAY_MS));
+  for (let i = 0; i < CRON_SEARCH_DAYS; i += 1) {
+    const year = day.getUTCFullYear();
+    const month = day.getUTCMonth() + 1;
+    if (cronMatchesDay(parsed.cron, year, month, day.getUTCDate())) {
+      for (const hour of hours) {
+        for (const minute of minutes) {
+          const wallTime = day.getTime() + (hour * 60 + minute) * 60_000;
+          if (wallTime < start) continue;
+          const instant = instantForWallTime(formatter, wallTime);
+          if (instant > after) return new Date(instant);
+        }
+      }
+    }
+    day.setUTCDate(day.getUTCDate() + 1);
+  }
+  return null;
+}
+
+function isValidTimeZone(timeZone: string) {
+  try {
+    new Intl.DateTimeFormat("en-US", { timeZone });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+const BLACKOUT_WEEKDAYS = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+function parseBlackoutTimeOfDay(raw: string) {
+  const match = /^(\d{1,2}):(\d{1,2})$/.exec(raw);
+  if (!match) return null;
+  const hour = Number(match[1]);
+  const minute = Number(match[2]);
+  if (minute > 59 || hour > 24 || (hour === 24 && minute !== 0)) return null;
+  return hour * 60 + minute;
+}
+
+function validateBlackoutWindow(spec: string) {
+  const tokens = spec.split(/\s+/).filter(Boolean);
+  if (tokens.at(-1) === "stop") tokens.pop();
+  const [first, second] = tokens;
+
+  if (tokens.length === 1 && first?.includes("/")) {
+    const separator = first.indexOf("/");
+    const startsAt = Date.parse(first.slice(0, separator));
+    const endsAt = Date.parse(first.slice(separator + 1));
+    if (Number.isNaN(startsAt) || Number.isNaN(endsAt)) return "invalid time (expected RFC 3339)";
+    return endsAt > startsAt ? null : "end must be after start";
+  }
+
+  if (tokens.length === 2 && first && second) {
+    const validDays =
+      first === "*" ||
+      first.split(",").every((part) => {
+        const days = part.split("-");
+        return (
+          days.length <= 2 && days.every((day) => BLACKOUT_WEEKDAYS.includes(day.toLowerCase()))
+        );
+      });
+    if (!validDays) return `unknown days '${first}' (expected sun..sat)`;
+    const [start, end, ...rest] = second.split("-");
+    const startMinute = parseBlackoutTimeOfDay(start ?? "");
+    const endMinute = parseBlackoutTimeOfDay(end ?? "");
+    if (rest.length > 0 || startMinute === null || endMinute === null) {
+      return `invalid time range '${second}' (expected HH:MM-HH:MM)`;
     }
-    cursor.setMinutes(cursor.getMinutes() + 1);
+    return startMinute === endMinute ? "start and end time must differ" : null;
+  }
+
+  return (
+    "expected '<days> <HH:MM>-<HH:MM>' or '<start>/<end>' in RFC 3339, " +
+    "optionally followed by 'stop'"
+  );
+}
+
+function findBlackoutWindowError(specs: string[]) {
+  for (const spec of specs) {
+    const reason = validateBlackoutWindow(spec);
+    if (reason) return `Invalid blackout window '${spec}': ${reason}`;
   }
   return null;
 }
@@ -1256,6 +1674,15 @@ function mapBackupPlan(
     keepMonthly: record.keepMonthly,
     keepYearly: record.keepYearly,
     keepWithin: record.keepWithin,
+    timeZone: record.timeZone,
+    overlapPolicy: record.overlapPolicy,
+    jitterSeconds: record.jitterSeconds,
+    jitterMode: record.jitterMode,
+    blackoutWindows: parseStringArrayJson(record.blackoutWindowsJson),
+    retry: parseRetryPolicyJson(record.retryJson),
+    timeoutSeconds: record.timeoutSeconds,
+    operation: record.operation,
+    readDataSubset: record.readDataSubset,
     repository: {
       id: repository.id,
       name: repository.name,
@@ -1608,6 +2035,15 @@ async function getBackupPlansForUser(userId: string) {
         keepMonthly: true,
         keepYearly: true,
         keepWithin: true,
+        timeZone: true,
+        overlapPolicy: true,
+        jitterSeconds: true,
+        jitterMode: true,
+        blackoutWindowsJson: true,
+        retryJson: true,
+        timeoutSeconds: true,
+        operation: true,
+        readDataSubset: true,
         createdAt: true,
         updatedAt: true,
       },
@@ -2414,9 +2850,9 @@ async function enqueueBackupPlanRuns(plan: BackupPlanRecord) {
   const runGroupId = crypto.randomUUID();
   const planPathsConfig = parsePlanPathsConfig(plan.pathsJson);
   const tags = parseStringArrayJson(plan.tagsJson);
-  const nextRunAt = plan.enabled ? computeNextRun(plan.cron, startedAtDate) : null;
+  const nextRunAt = plan.enabled ? computeNextRun(plan.cron, startedAtDate, plan.timeZone) : null;
 
-  if (!hasAnyPlanPaths(planPathsConfig)) {
+  if (plan.operation === "backup" && !hasAnyPlanPaths(planPathsConfig)) {
     const durationMs = Date.now() - startedAtMs;
     await db
       .update(backupPlan)
@@ -2542,7 +2978,7 @@ async function enqueueBackupPlanRuns(plan: BackupPlanRecord) {
     const workerPaths = resolvePathsForWorker(planPathsConfig, workerId);
     const runId = crypto.randomUUID();
 
-    if (workerPaths.length === 0) {
+    if (plan.operation === "backup" && workerPaths.length === 0) {
       const errorMessage = "No backup paths configured for worker";
       if (!firstQueueError) firstQueueError = errorMessage;
       await db.insert(backupPlanRun).values({
@@ -2788,7 +3224,7 @@ async function executeBackupPlan(plan: BackupPlanRecord) {
   const runGroupId = crypto.randomUUID();
   const planPathsConfig = parsePlanPathsConfig(plan.pathsJson);
   const tags = parseStringArrayJson(plan.tagsJson);
-  const nextRunAt = plan.enabled ? computeNextRun(plan.cron, startedAtDate) : null;
+  const nextRunAt = plan.enabled ? computeNextRun(plan.cron, startedAtDate, plan.timeZone) : null;
 
   if (!hasAnyPlanPaths(planPathsConfig)) {
     const durationMs = Date.now() - startedAtMs;
@@ -3234,6 +3670,15 @@ async function runDueBackupPlans() {
         keepMonthly: true,
         keepYearly: true,
         keepWithin: true,
+        timeZone: true,
+        overlapPolicy: true,
+        jitterSeconds: true,
+        jitterMode: true,
+        blackoutWindowsJson: true,
+        retryJson: true,
+        timeoutSeconds: true,
+        operation: true,
+        readDataSubset: true,
         createdAt: true,
         updatedAt: true,
       },
@@ -5209,8 +5654,8 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
       }
 
       const parsedCron = parseCronExpression(body.cron);
-      if (!parsedCron) {
-        return status(400, { error: "Invalid cron expression (expected 5-part cron)" });
+      if (!parsedCron.ok) {
+        return status(400, { error: `Invalid cron expression: ${parsedCron.error}` });
       }
 
       const repository = await db.query.rusticRepository.findFirst({
@@ -5232,8 +5677,6 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
         });
       }
 
-      const now = new Date();
-      const nextRunAt = body.enabled === false ? null : computeNextRun(body.cron, now);
       const planId = crypto.randomUUID();
       const workerPathRulesResult = sanitizeWorkerPathRules(body.workerPathRules, workerIds);
       if (!workerPathRulesResult.ok) {
@@ -5243,10 +5686,28 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
         defaultPaths: normalizePaths(body.paths),
         workerPaths: workerPathRulesResult.rules,
       };
-      if (!hasAnyPlanPaths(pathsConfig)) {
+      const operation = body.operation ?? "backup";
+      if (operation === "backup" && !hasAnyPlanPaths(pathsConfig)) {
         return status(400, { error: "At least one backup path is required" });
       }
 
+      const timeZone = body.timeZone?.trim() || null;
+      if (timeZone && !isValidTimeZone(timeZone)) {
+        return status(400, {
+          error: "Invalid time zone (expected an IANA name such as Europe/Berlin)",
+        });
+      }
+      const nextRunAt =
+        body.enabled === false ? null : computeNextRun(body.cron, new Date(), timeZone);
+
+      const blackoutWindows = (body.blackoutWindows ?? [])
+        .map((spec) => spec.trim())
+        .filter(Boolean);
+      const blackoutWindowError = findBlackoutWindowError(blackoutWindows);
+      if (blackoutWindowError) {
+        return status(400, { error: blackoutWindowError });
+      }
+
       await db.insert(backupPlan).values({
         id: planId,
         userId: user.id,
@@ -5269,6 +5730,15 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
         keepMonthly: body.keepMonthly ?? null,
         keepYearly: body.keepYearly ?? null,
         keepWithin: body.keepWithin?.trim() || null,
+        timeZone,
+        overlapPolicy: body.overlapPolicy ?? "skip",
+        jitterSeconds: body.jitterSeconds ?? 0,
+        jitterMode: body.jitterMode ?? "deterministic",
+        blackoutWindowsJson: blackoutWindows.length > 0 ? JSON.stringify(blackoutWindows) : null,
+        retryJson: body.retry ? JSON.stringify(body.retry) : null,
+        timeoutSeconds: body.timeoutSeconds ?? null,
+        operation,
+        readDataSubset: body.readDataSubset?.trim() || null,
       });
       await replacePlanWorkers(planId, workerIds);
 
@@ -5342,6 +5812,15 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
           keepMonthly: true,
           keepYearly: true,
           keepWithin: true,
+          timeZone: true,
+          overlapPolicy: true,
+          jitterSeconds: true,
+          jitterMode: true,
+          blackoutWindowsJson: true,
+          retryJson: true,
+          timeoutSeconds: true,
+          operation: true,
+          readDataSubset: true,
         },
       });
       if (!existing) {
@@ -5349,8 +5828,9 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
       }
 
       const nextCron = body.cron?.trim() ?? existing.cron;
-      if (!parseCronExpression(nextCron)) {
-        return status(400, { error: "Invalid cron expression (expected 5-part cron)" });
+      const parsedCron = parseCronExpression(nextCron);
+      if (!parsedCron.ok) {
+        return status(400, { error: `Invalid cron expression: ${parsedCron.error}` });
       }
 
       let nextRepositoryId = body.repositoryId ?? existing.repositoryId;
@@ -5391,7 +5871,6 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
       }
 
       const nextEnabled = body.enabled ?? existing.enabled;
-      const nextRunAt = nextEnabled ? computeNextRun(nextCron, new Date()) : null;
       const workerPathRulesResult = sanitizeWorkerPathRules(body.workerPathRules, nextWorkerIds);
       if (!workerPathRulesResult.ok) {
         return status(400, { error: workerPathRulesResult.error });
@@ -5408,10 +5887,29 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
         defaultPaths: nextDefaultPaths,
         workerPaths: nextWorkerPathRules,
       };
-      if (!hasAnyPlanPaths(nextPathsConfig)) {
+      const nextOperation = body.operation ?? existing.operation;
+      if (nextOperation === "backup" && !hasAnyPlanPaths(nextPathsConfig)) {
         return status(400, { error: "At least one backup path is required" });
       }
 
+      const nextTimeZone =
+        body.timeZone === null ? null : body.timeZone?.trim() || existing.timeZone;
+      if (nextTimeZone && !isValidTimeZone(nextTimeZone)) {
+        return status(400, {
+          error: "Invalid time zone (expected an IANA name such as Europe/Berlin)",
+        });
+      }
+      const nextRunAt = nextEnabled ? computeNextRun(nextCron, new Date(), nextTimeZone) : null;
+
+      const nextBlackoutWindows =
+        body.blackoutWindows === undefined
+          ? parseStringArrayJson(existing.blackoutWindowsJson)
+          : body.blackoutWindows.map((spec) => spec.trim()).filter(Boolean);
+      const blackoutWindowError = findBlackoutWindowError(nextBlackoutWindows);
+      if (blackoutWindowError) {
+        return status(400, { error: blackoutWindowError });
+      }
+
       await db
         .update(backupPlan)
         .set({
@@ -5439,6 +5937,25 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
           keepYearly: body.keepYearly === null ? null : (body.keepYearly ?? existing.keepYearly),
           keepWithin:
             body.keepWithin === null ? null : body.keepWithin?.trim() || existing.keepWithin,
+          timeZone: nextTimeZone,
+          overlapPolicy: body.overlapPolicy ?? existing.overlapPolicy,
+          jitterSeconds: body.jitterSeconds ?? existing.jitterSeconds,
+          jitterMode: body.jitterMode ?? existing.jitterMode,
+          blackoutWindowsJson:
+            nextBlackoutWindows.length > 0 ? JSON.stringify(nextBlackoutWindows) : null,
+          retryJson:
+            body.retry === undefined
+              ? existing.retryJson
+              : body.retry
+                ? JSON.stringify(body.retry)
+                : null,
+          timeoutSeconds:
+            body.timeoutSeconds === null ? null : (body.timeoutSeconds ?? existing.timeoutSeconds),
+          operation: nextOperation,
+          readDataSubset:
+            body.readDataSubset === null
+              ? null
+              : body.readDataSubset?.trim() || existing.readDataSubset,
         })
         .where(eq(backupPlan.id, existing.id));
       await replacePlanWorkers(existing.id, nextWorkerIds);
@@ -5610,6 +6127,15 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
           keepMonthly: true,
           keepYearly: true,
           keepWithin: true,
+          timeZone: true,
+          overlapPolicy: true,
+          jitterSeconds: true,
+          jitterMode: true,
+          blackoutWindowsJson: true,
+          retryJson: true,
+          timeoutSeconds: true,
+          operation: true,
+          readDataSubset: true,
           createdAt: true,
           updatedAt: true,
         },
@@ -5700,6 +6226,15 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
           keepMonthly: true,
           keepYearly: true,
           keepWithin: true,
+          timeZone: true,
+          overlapPolicy: true,
+          jitterSeconds: true,
+          jitterMode: true,
+          blackoutWindowsJson: true,
+          retryJson: true,
+          timeoutSeconds: true,
+          operation: true,
+          readDataSubset: true,
           createdAt: true,
           updatedAt: true,
         },
@@ -5727,7 +6262,10 @@ export const rusticRoutes = new Elysia({ prefix: "/api" })
           if (body.action === "resume") {
             await db
               .update(backupPlan)
-              .set({ enabled: true, nextRunAt: computeNextRun(plan.cron, new Date()) })
+              .set({
+                enabled: true,
+                nextRunAt: computeNextRun(plan.cron, new Date(), plan.timeZone),
+              })
               .where(eq(backupPlan.id, plan.id));
             results.push({ planId, ok: true, message: "Resumed" });
             continue;
@@ -20,34 +20,135 @@ const createWorkerType = type({ name: "string", workerIp: "string", "region?": "
 const updateWorkerType = type({ name: "string", "region?": "string | null" });
 const rotateTokenParamsType = type({ id: "string.uuid" });
 const workerParamsType = type({ id: "string.uuid" });
+const workerJobProgressType = type({
+  "message?": "string | null",
+  "filesDone?": "number.integer >= 0 | null",
+  "filesTotal?": "number.integer >= 0 | null",
+  "bytesDone?": "number.integer >= 0 | null",
+  "bytesTotal?": "number.integer >= 0 | null",
+  "percentDone?": "number | null",
+  "etaSeconds?": "number.integer >= 0 | null",
+  updatedAt: "string",
+});
+const workerJobType = type({
+  id: "string <= 256",
+  kind: "string <= 64",
+  "planId?": "string | null",
+  "repository?": "string | null",
+  startedAt: "string",
+  elapsedMs: "number.integer >= 0",
+  "pid?": "number.integer >= 0 | null",
+  phase: '"starting" | "waiting" | "queued" | "running" | "terminating"',
+  cancelled: "boolean",
+  "progress?": workerJobProgressType.or("null"),
+});
 const syncWorkerStatsType = type({
   status: '"online" | "degraded"',
   "endpoint?": "string.url <= 2048",
   uptimeMs: "number.integer >= 0",
   requestsTotal: "number.integer >= 0",
   errorTotal: "number.integer >= 0",
+  "jobs?": workerJobType.array(),
 });
 const claimBackupRunsType = type({
   "limit?": "number.integer >= 1",
 });
+const planOperationType = type(
+  '"backup" | "check" | "check_read_data_subset" | "forget" | "prune" | "repair_index"',
+);
 const completeBackupRunType = type({
-  status: '"success" | "failed"',
+  "operation?": planOperationType,
+  status: '"success" | "failed" | "timeout" | "cancelled"',
   "error?": "string <= 4096 | null",
   "durationMs?": "number.integer >= 0",
+  "queuedMs?": "number.integer >= 0",
   "snapshotId?": "string <= 512 | null",
   "snapshotTime?": "string | null",
   "output?": "unknown",
 });
+const backupAttemptType = type({
+  attempt: "number.integer >= 1",
+  status: '"success" | "failed" | "timeout" | "cancelled"',
+  "error?": "string <= 4096 | null",
+  "errorClass?": "string | null",
+  durationMs: "number.integer >= 0",
+});
 const reportBackupPlanRunType = type({
-  status: '"success" | "failed"',
+  "operation?": planOperationType,
+  status: '"success" | "failed" | "timeout" | "cancelled" | "skipped" | "deferred"',
   "error?": "string <= 4096 | null",
   "durationMs?": "number.integer >= 0",
+  "queuedMs?": "number.integer >= 0",
   "snapshotId?": "string <= 512 | null",
   "snapshotTime?": "string | null",
   "nextRunAt?": "string | null",
+  "scheduledAt?": "string | null",
+  "late?": "boolean",
+  "attempts?": backupAttemptType.array(),
   "output?": "unknown",
 });
 
+const backupReportEvents = {
+  success: {
+    type: "backup_completed",
+    status: "resolved",
+    severity: "info",
+    message: "Backup completed",
+  },
+  failed: {
+    type: "backup_failed",
+    status: "open",
+    severity: "error",
+    message: "Backup command failed",
+  },
+  timeout: {
+    type: "backup_timed_out",
+    status: "open",
+    severity: "error",
+    message: "Backup timed out",
+  },
+  cancelled: {
+    type: "backup_cancelled",
+    status: "resolved",
+    severity: "warning",
+    message: "Backup cancelled",
+  },
+  skipped: {
+    type: "backup_skipped",
+    status: "resolved",
+    severity: "warning",
+    message: "Backup skipped",
+  },
+  deferred: {
+    type: "backup_deferred",
+    status: "resolved",
+    severity: "info",
+    message: "Backup deferred",
+  },
+} as const;
+
+const planOperationLabels = {
+  backup: "Backup",
+  check: "Check",
+  check_read_data_subset: "Data check",
+  forget: "Forget",
+  prune: "Prune",
+  repair_index: "Index repair",
+} as const;
+
+function planReportEvent(
+  operation: typeof planOperationType.infer,
+  reportStatus: keyof typeof backupReportEvents,
+) {
+  const event = backupReportEvents[reportStatus];
+  if (operation === "backup") return event;
+  return {
+    ...event,
+    type: event.type.replace("backup_", `${operation}_`),
+    message: event.message.replace("Backup", planOperationLabels[operation]),
+  };
+}
+
 const createWorkerSchema = {
   safeParse(input: unknown) {
     if (!createWorkerType.allows(input)) {
@@ -167,6 +268,7 @@ const reportBackupPlanRunSchema = {
 };
 
 const WORKER_ONLINE_THRESHOLD_MS = 45_000;
+const workerActiveJobs = new Map<string, (typeof workerJobType.infer)[]>();
 const BASE32_ALPHABET = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
 
 type PlanPathsConfig = {
@@ -321,6 +423,9 @@ function mapWorkerResponse(record: {
       return null;
     }
   })();
+  const isOnline =
+    record.lastSeenAt !== null &&
+    Date.now() - new Date(record.lastSeenAt).getTime() <= WORKER_ONLINE_THRESHOLD_MS;
   return {
     id: record.id,
     name: record.name,
@@ -331,9 +436,8 @@ function mapWorkerResponse(record: {
     uptimeMs: record.uptimeMs,
     requestsTotal: record.requestsTotal,
     errorTotal: record.errorTotal,
-    isOnline:
-      record.lastSeenAt !== null &&
-      Date.now() - new Date(record.lastSeenAt).getTime() <= WORKER_ONLINE_THRESHOLD_MS,
+    isOnline,
+    activeJobs: isOnline ? (workerActiveJobs.get(record.id) ?? []) : [],
     createdAt: record.createdAt,
     updatedAt: record.updatedAt,
   };
@@ -382,6 +486,22 @@ function parseOptionsJson(optionsJson: string | null): Record<string, string> {
   }
 }
 
+function parseRetryPolicyJson(value: string | null): Record<string, unknown> | null {
+  if (!value) {
+    return null;
+  }
+
+  try {
+    const parsed = JSON.parse(value) as unknown;
+    if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+      return null;
+    }
+    return parsed as Record<string, unknown>;
+  } catch {
+    return null;
+  }
+}
+
 function parseStringArrayJson(value: string | null): string[] {
   if (!value) return [];
   try {
@@ -567,6 +687,31 @@ function deriveRcloneRepositoryForInit(
   return normalizeRcloneRepository(repositoryPath, repositoryId, options);
 }
 
+function mapPlanRetention(plan: {
+  operation: string;
+  pruneEnabled: boolean;
+  keepLast: number | null;
+  keepDaily: number | null;
+  keepWeekly: number | null;
+  keepMonthly: number | null;
+  keepYearly: number | null;
+  keepWithin: string | null;
+}) {
+  if (!plan.pruneEnabled && plan.operation !== "forget") return null;
+  const keepWithin = plan.keepWithin?.trim() || null;
+  const retention: Record<string, number | string> = {};
+  if (plan.keepLast != null) retention.keepLast = plan.keepLast;
+  if (plan.keepDaily != null) retention.keepDaily = plan.keepDaily;
+  if (plan.keepWeekly != null) retention.keepWeekly = plan.keepWeekly;
+  if (plan.keepMonthly != null) retention.keepMonthly = plan.keepMonthly;
+  if (plan.keepYearly != null) retention.keepYearly = plan.keepYearly;
+  if (keepWithin) retention.keepWithin = keepWithin;
+  if (Object.keys(retention).length === 0) return null;
+  // pruneEnabled means forget and prune after every run; hourly keeps the worker close to that.
+  if (plan.pruneEnabled) retention.pruneIntervalHours = 1;
+  return retention;
+}
+
 export const workerRoutes = new Elysia()
   .get("/api/workers", async ({ request, status }) => {
     const user = await getAuthenticatedUser(request);
@@ -725,6 +870,7 @@ export const workerRoutes = new Elysia()
     }
 
     await db.delete(worker).where(eq(worker.id, existingWorker.id));
+    workerActiveJobs.delete(existingWorker.id);
 
     return new Response(null, { status: 204 });
   })
@@ -818,6 +964,7 @@ export const workerRoutes = new Elysia()
       logWarn("worker sync rejected: invalid payload", { workerId: auth.workerId });
       return status(400, { error: "Invalid sync payload" });
     }
+    workerActiveJobs.set(auth.workerId, parsed.data.jobs ?? []);
 
     const previousWorkerState = await db.query.worker.findFirst({
       where: (table, { eq }) => eq(table.id, auth.workerId),
@@ -920,7 +1067,7 @@ export const workerRoutes = new Elysia()
 
     return new Response(null, { status: 204 });
   })
-  .post("/api/workers/backup-plans/sync", async ({ request, status }) => {
+  .post("/api/workers/backup-plans/sync", async ({ request, set, status }) => {
     const auth = await authenticateWorkerFromSyncToken(request.headers);
     if (!auth) {
       return status(401, { error: "Unauthorized" });
@@ -937,6 +1084,22 @@ export const workerRoutes = new Elysia()
          p.tags_json AS "tagsJson",
          p.dry_run AS "dryRun",
          p.enabled,
+         p.time_zone AS "timeZone",
+         p.overlap_policy AS "overlapPolicy",
+         p.jitter_seconds AS "jitterSeconds",
+         p.jitter_mode AS "jitterMode",
+         p.blackout_windows_json AS "blackoutWindowsJson",
+         p.retry_json AS "retryJson",
+         p.timeout_seconds AS "timeoutSeconds",
+         p.prune_enabled AS "pruneEnabled",
+         p.keep_last AS "keepLast",
+         p.keep_daily AS "keepDaily",
+         p.keep_weekly AS "keepWeekly",
+         p.keep_monthly AS "keepMonthly",
+         p.keep_yearly AS "keepYearly",
+         p.keep_within AS "keepWithin",
+         p.operation,
+         p.read_data_subset AS "readDataSubset",
          p.updated_at AS "updatedAt",
          r.backend,
          r.repository,
@@ -964,6 +1127,22 @@ export const workerRoutes = new Elysia()
         tagsJson: string | null;
         dryRun: boolean;
         enabled: boolean;
+        timeZone: string | null;
+        overlapPolicy: string;
+        jitterSeconds: number;
+        jitterMode: string;
+        blackoutWindowsJson: string | null;
+        retryJson: string | null;
+        timeoutSeconds: number | null;
+        pruneEnabled: boolean;
+        keepLast: number | null;
+        keepDaily: number | null;
+        keepWeekly: number | null;
+        keepMonthly: number | null;
+        keepYearly: number | null;
+        keepWithin: string | null;
+        operation: string;
+        readDataSubset: string | null;
         updatedAt: Date | string;
         backend: string;
         repository: string;
@@ -974,7 +1153,7 @@ export const workerRoutes = new Elysia()
       .map((row) => {
         const pathsConfig = parsePlanPathsConfig(row.pathsJson);
         const paths = resolvePathsForWorker(pathsConfig, auth.workerId);
-        if (paths.length === 0) {
+        if (paths.length === 0 && row.operation === "backup") {
           return null;
         }
 
@@ -992,6 +1171,7 @@ export const workerRoutes = new Elysia()
         const repository = shouldForceRcloneBackup
           ? deriveRcloneRepositoryForInit(row.repository, row.repositoryId, backupOptions)
           : row.repository;
+        const retention = mapPlanRetention(row);
 
         return {
           id: row.id,
@@ -1001,6 +1181,15 @@ export const workerRoutes = new Elysia()
           cron: row.cron,
           enabled: row.enabled,
           updatedAt: new Date(row.updatedAt).toISOString(),
+          timeZone: row.timeZone ?? undefined,
+          overlapPolicy: row.overlapPolicy,
+          jitterSeconds: row.jitterSeconds,
+          jitterMode: row.jitterMode,
+          blackoutWindows: parseStringArrayJson(row.blackoutWindowsJson),
+          retry: parseRetryPolicyJson(row.retryJson) ?? undefined,
+          timeoutSeconds: row.timeoutSeconds ?? undefined,
+          operation: row.operation,
+          readDataSubset: row.readDataSubset ?? undefined,
           request: {
             backend,
             options: backupOptions,
@@ -1010,11 +1199,23 @@ export const workerRoutes = new Elysia()
             tags: parseStringArrayJson(row.tagsJson),
             dryRun: row.dryRun,
           },
+          ...(retention ? { retention } : {}),
         };
       })
       .filter((value): value is NonNullable<typeof value> => Boolean(value));
 
-    return { plans };
+    const version = createHash("sha256").update(JSON.stringify(plans)).digest("hex");
+    const knownVersion = request.headers
+      .get("if-none-match")
+      ?.replace(/^W\//, "")
+      .replaceAll('"', "")
+      .trim();
+    if (knownVersion === version) {
+      return new Response(null, { status: 304, headers: { etag: `"${version}"` } });
+    }
+
+    set.headers.etag = `"${version}"`;
+    return { version, plans };
   })
   .post("/api/workers/backup-plans/:id/report", async ({ request, params, body, status }) => {
     const auth = await authenticateWorkerFromSyncToken(request.headers);
@@ -1053,6 +1254,9 @@ export const workerRoutes = new Elysia()
     }
 
     const runId = crypto.randomUUID();
+    const operation = parsedBody.data.operation ?? "backup";
+    const operationLabel = planOperationLabels[operation];
+    const reportEvent = planReportEvent(operation, parsedBody.data.status);
     const startedAt = new Date(Date.now() - (parsedBody.data.durationMs ?? 0));
     const finishedAt = new Date();
 
@@ -1063,7 +1267,7 @@ export const workerRoutes = new Elysia()
         userId: plan.userId,
         repositoryId: plan.repositoryId,
         workerId: auth.workerId,
-        type: "backup",
+        type: operation,
         status: parsedBody.data.status,
         error: parsedBody.data.error ?? null,
         durationMs: parsedBody.data.durationMs ?? null,
@@ -1081,21 +1285,27 @@ export const workerRoutes = new Elysia()
         planId: plan.id,
         runId,
         workerId: auth.workerId,
-        type: parsedBody.data.status === "success" ? "backup_completed" : "backup_failed",
-        status: parsedBody.data.status === "success" ? "resolved" : "open",
-        severity: parsedBody.data.status === "success" ? "info" : "error",
+        type: reportEvent.type,
+        status: reportEvent.status,
+        severity: reportEvent.severity,
         message:
           parsedBody.data.status === "success"
-            ? "Backup completed"
-            : parsedBody.data.error || "Backup command failed",
+            ? parsedBody.data.late
+              ? `${operationLabel} completed (late run)`
+              : reportEvent.message
+            : parsedBody.data.error || reportEvent.message,
         detailsJson: JSON.stringify({
           snapshotId: parsedBody.data.snapshotId ?? null,
           snapshotTime: parsedBody.data.snapshotTime ?? null,
+          scheduledAt: parsedBody.data.scheduledAt ?? null,
+          late: parsedBody.data.late ?? false,
+          queuedMs: parsedBody.data.queuedMs ?? 0,
+          attempts: parsedBody.data.attempts ?? [],
         }),
       });
     });
 
-    if (parsedBody.data.status === "success") {
+    if (parsedBody.data.status === "success" && operation === "backup") {
       await recordStorageUsageSample({
         userId: plan.userId,
         repositoryId: plan.repositoryId,
@@ -1142,12 +1352,12 @@ export const workerRoutes = new Elysia()
       }
     }
 
-    if (parsedBody.data.status === "failed") {
+    if (parsedBody.data.status === "failed" || parsedBody.data.status === "timeout") {
       const delivered = await sendDiscordNotification({
         userId: plan.userId,
         category: "backup_failures",
-        title: "Backup plan run failed",
-        message: parsedBody.data.error || "Backup command failed",
+        title: `${operationLabel} plan run failed`,
+        message: parsedBody.data.error || `${operationLabel} command failed`,
         severity: "error",
         fields: [
           { name: "Plan ID", value: plan.id },
@@ -1165,13 +1375,26 @@ export const workerRoutes = new Elysia()
       }
     }
 
+    if (parsedBody.data.status === "skipped" || parsedBody.data.status === "deferred") {
+      await db
+        .update(backupPlan)
+        .set({
+          nextRunAt: parsedBody.data.nextRunAt ? new Date(parsedBody.data.nextRunAt) : null,
+        })
+        .where(eq(backupPlan.id, plan.id));
+
+      return new Response(null, { status: 204 });
+    }
+
     await db
       .update(backupPlan)
       .set({
         lastRunAt: finishedAt,
         lastStatus: parsedBody.data.status,
         lastError:
-          parsedBody.data.status === "success" ? null : (parsedBody.data.error ?? "Backup failed"),
+          parsedBody.data.status === "success"
+            ? null
+            : (parsedBody.data.error ?? `${operationLabel} failed`),
         lastDurationMs: parsedBody.data.durationMs ?? null,
         nextRunAt: parsedBody.data.nextRunAt ? new Date(parsedBody.data.nextRunAt) : null,
       })
@@ -1262,6 +1485,7 @@ export const workerRoutes = new Elysia()
       return status(400, { error: "Invalid completion payload" });
     }
 
+    const operation = parsedBody.data.operation ?? "backup";
     const completedAt = new Date();
     const completed = await db.$client.query(
       `UPDATE "backup_plan_run"
@@ -1272,7 +1496,8 @@ export const workerRoutes = new Elysia()
          "snapshot_id" = $4,
          "snapshot_time" = $5,
          "output_json" = $6,
-         "finished_at" = $7
+         "finished_at" = $7,
+         "type" = $10
        WHERE
          "id" = $8
          AND "worker_id" = $9
@@ -1293,6 +1518,7 @@ export const workerRoutes = new Elysia()
         completedAt,
         parsedParams.data,
         auth.workerId,
+        operation,
       ],
     );
 
@@ -1309,7 +1535,7 @@ export const workerRoutes = new Elysia()
       return status(404, { error: "Running backup run not found" });
     }
 
-    if (parsedBody.data.status === "success") {
+    if (parsedBody.data.status === "success" && operation === "backup") {
       await recordStorageUsageSample({
         userId: completionRow.userId,
         repositoryId: completionRow.repositoryId,
@@ -1372,11 +1598,11 @@ export const workerRoutes = new Elysia()
         SELECT
           COUNT(*)::int AS "totalCount",
           SUM(CASE WHEN "status" = 'success' THEN 1 ELSE 0 END)::int AS "successCount",
-          SUM(CASE WHEN "status" = 'failed' THEN 1 ELSE 0 END)::int AS "failureCount",
+          SUM(CASE WHEN "status" IN ('failed', 'timeout', 'cancelled') THEN 1 ELSE 0 END)::int AS "failureCount",
           SUM(CASE WHEN "status" IN ('pending', 'running') THEN 1 ELSE 0 END)::int AS "unfinishedCount",
           MIN("started_at") AS "firstStartedAt",
           MAX("finished_at") AS "lastFinishedAt",
-          MAX("error") FILTER (WHERE "status" = 'failed' AND "error" IS NOT NULL) AS "lastError"
+          MAX("error") FILTER (WHERE "status" IN ('failed', 'timeout', 'cancelled') AND "error" IS NOT NULL) AS "lastError"
         FROM "backup_plan_run"
         WHERE "run_group_id" = ${completionRow.runGroupId} AND "plan_id" = ${completionRow.planId}
       `);
@@ -0,0 +1 @@
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "overlap_policy" text DEFAULT 'skip' NOT NULL;
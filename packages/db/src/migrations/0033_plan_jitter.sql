@@ -0,0 +1,2 @@
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "jitter_seconds" integer DEFAULT 0 NOT NULL;--> statement-breakpoint
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "jitter_mode" text DEFAULT 'deterministic' NOT NULL;
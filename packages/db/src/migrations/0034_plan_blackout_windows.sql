@@ -0,0 +1 @@
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "blackout_windows_json" text;
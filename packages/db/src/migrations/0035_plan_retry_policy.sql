@@ -0,0 +1 @@
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "retry_json" text;
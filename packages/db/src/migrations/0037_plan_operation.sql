@@ -0,0 +1,2 @@
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "operation" text DEFAULT 'backup' NOT NULL;--> statement-breakpoint
+ALTER TABLE "backup_plan" ADD COLUMN IF NOT EXISTS "read_data_subset" text;
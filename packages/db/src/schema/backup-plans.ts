@@ -36,6 +36,15 @@ export const backupPlan = pgTable(
     keepMonthly: integer("keep_monthly"),
     keepYearly: integer("keep_yearly"),
     keepWithin: text("keep_within"),
+    timeZone: text("time_zone"),
+    overlapPolicy: text("overlap_policy").default("skip").notNull(),
+    jitterSeconds: integer("jitter_seconds").default(0).notNull(),
+    jitterMode: text("jitter_mode").default("deterministic").notNull(),
+    blackoutWindowsJson: text("blackout_windows_json"),
+    retryJson: text("retry_json"),
+    timeoutSeconds: integer("timeout_seconds"),
+    operation: text("operation").default("backup").notNull(),
+    readDataSubset: text("read_data_subset"),
     runLeaseUntil: timestamp("run_lease_until"),
     runLeaseOwner: text("run_lease_owner"),
     createdAt: timestamp("created_at").defaultNow().notNull(),